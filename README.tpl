@@ -65,6 +65,10 @@ serde = { version = "1.0", features = ["derive"] }
 
 This crate is `async` but you can choose to use an async runtime like [tokio](https://crates.io/crates/tokio) or just [block on futures](https://docs.rs/futures/latest/futures/executor/fn.block_on.html).
 You can enable the `sync` feature to make most structs `Sync`. It may be a bit slower.
+The HTTP client uses [rustls](https://crates.io/crates/rustls) by default. To use the TLS library of your system instead, set `default-features = false` and enable the `native-tls` feature.
+When the default features are disabled, the `reqwest` feature must be enabled along with one of `rustls-tls` or `native-tls`.
+
+If you don't want to deal with futures at all, enable the `blocking` feature and use the `meilisearch_sdk::blocking::BlockingClient`.
 
 Using this crate is possible without [serde](https://crates.io/crates/serde), but a lot of features require serde.
 
@@ -7,6 +7,14 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::io::stdin;
 
+thread_local! {
+    // `awc::Client` can't be shared between threads, so it can't be stored in `AwcClient`.
+    // Build it once per thread instead, so its connection pool is reused by every request.
+    static AWC_CLIENT: awc::Client = awc::ClientBuilder::new()
+        .add_default_header(("User-Agent", "Rust client with Awc"))
+        .finish();
+}
+
 #[derive(Debug, Clone)]
 pub struct AwcClient {
     api_key: Option<String>,
@@ -33,12 +41,7 @@ impl HttpClient for AwcClient {
         content_type: &str,
         expected_status_code: u16,
     ) -> Result<Output, Error> {
-        let mut builder = awc::ClientBuilder::new();
-        if let Some(ref api_key) = self.api_key {
-            builder = builder.bearer_auth(api_key);
-        }
-        builder = builder.add_default_header(("User-Agent", "Rust client with Awc"));
-        let client = builder.finish();
+        let client = AWC_CLIENT.with(awc::Client::clone);
 
         let query = method.query();
         let query = yaup::to_string(query)?;
@@ -50,7 +53,10 @@ impl HttpClient for AwcClient {
         };
 
         let url = add_query_parameters(&url, method.query())?;
-        let request = client.request(verb(&method), &url);
+        let mut request = client.request(verb(&method), &url);
+        if let Some(ref api_key) = self.api_key {
+            request = request.bearer_auth(api_key);
+        }
 
         let mut response = if let Some(body) = method.into_body() {
             let reader = tokio_util::compat::FuturesAsyncReadCompatExt::compat(body);
@@ -1,8 +1,8 @@
 use convert_case::{Case, Casing};
 use proc_macro2::Ident;
 use quote::quote;
-use structmeta::{Flag, StructMeta};
-use syn::{parse_macro_input, spanned::Spanned};
+use structmeta::{Flag, NameArgs, StructMeta};
+use syn::{ext::IdentExt, parse_macro_input, spanned::Spanned};
 
 #[derive(Clone, StructMeta, Default)]
 struct FieldAttrs {
@@ -14,6 +14,34 @@ struct FieldAttrs {
     sortable: Flag,
 }
 
+#[derive(StructMeta, Default)]
+struct StructAttrs {
+    max_total_hits: Option<syn::LitInt>,
+    max_values_per_facet: Option<syn::LitInt>,
+    ranking_rules: Option<NameArgs<Vec<syn::LitStr>>>,
+    stop_words: Option<NameArgs<Vec<syn::LitStr>>>,
+}
+
+impl StructAttrs {
+    /// Merges the parameters of every `#[index_config(..)]` attribute of the struct.
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<StructAttrs> {
+        let mut struct_attrs = StructAttrs::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("index_config"))
+        {
+            let parsed = attr.parse_args::<StructAttrs>()?;
+            struct_attrs.max_total_hits = parsed.max_total_hits.or(struct_attrs.max_total_hits);
+            struct_attrs.max_values_per_facet = parsed
+                .max_values_per_facet
+                .or(struct_attrs.max_values_per_facet);
+            struct_attrs.ranking_rules = parsed.ranking_rules.or(struct_attrs.ranking_rules);
+            struct_attrs.stop_words = parsed.stop_words.or(struct_attrs.stop_words);
+        }
+        Ok(struct_attrs)
+    }
+}
+
 #[proc_macro_derive(IndexConfig, attributes(index_config))]
 pub fn generate_index_settings(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as syn::DeriveInput);
@@ -29,7 +57,18 @@ pub fn generate_index_settings(input: proc_macro::TokenStream) -> proc_macro::To
 
     let struct_ident = &ast.ident;
 
-    let index_config_implementation = get_index_config_implementation(struct_ident, fields);
+    let rename_all = match get_serde_rename_all(&ast.attrs) {
+        Ok(rename_all) => rename_all,
+        Err(error) => return proc_macro::TokenStream::from(error.to_compile_error()),
+    };
+
+    let struct_attrs = match StructAttrs::parse(&ast.attrs) {
+        Ok(struct_attrs) => struct_attrs,
+        Err(error) => return proc_macro::TokenStream::from(error.to_compile_error()),
+    };
+
+    let index_config_implementation =
+        get_index_config_implementation(struct_ident, fields, &struct_attrs, rename_all.as_ref());
     proc_macro::TokenStream::from(quote! {
         #index_config_implementation
     })
@@ -38,6 +77,8 @@ pub fn generate_index_settings(input: proc_macro::TokenStream) -> proc_macro::To
 fn get_index_config_implementation(
     struct_ident: &Ident,
     fields: &syn::Fields,
+    struct_attrs: &StructAttrs,
+    rename_all: Option<&syn::LitStr>,
 ) -> proc_macro2::TokenStream {
     let mut primary_key_attribute = String::new();
     let mut distinct_key_attribute = String::new();
@@ -65,6 +106,12 @@ fn get_index_config_implementation(
             .cloned()
             .unwrap_or_default();
 
+        // Use the name serde serializes the field with, since it is the name of the attribute in Meilisearch
+        let field_name = match get_serialized_field_name(field, rename_all) {
+            Ok(field_name) => field_name,
+            Err(error) => return error.to_compile_error(),
+        };
+
         // Check if the primary key field is unique
         if attrs.primary_key.value() {
             if primary_key_found {
@@ -74,7 +121,7 @@ fn get_index_config_implementation(
                 )
                 .to_compile_error();
             }
-            primary_key_attribute = field.ident.clone().unwrap().to_string();
+            primary_key_attribute = field_name.clone();
             primary_key_found = true;
         }
 
@@ -84,24 +131,24 @@ fn get_index_config_implementation(
                 return syn::Error::new(field.span(), "Only one field can be marked as distinct")
                     .to_compile_error();
             }
-            distinct_key_attribute = field.ident.clone().unwrap().to_string();
+            distinct_key_attribute = field_name.clone();
             distinct_found = true;
         }
 
         if attrs.displayed.value() {
-            displayed_attributes.push(field.ident.clone().unwrap().to_string());
+            displayed_attributes.push(field_name.clone());
         }
 
         if attrs.searchable.value() {
-            searchable_attributes.push(field.ident.clone().unwrap().to_string());
+            searchable_attributes.push(field_name.clone());
         }
 
         if attrs.filterable.value() {
-            filterable_attributes.push(field.ident.clone().unwrap().to_string());
+            filterable_attributes.push(field_name.clone());
         }
 
         if attrs.sortable.value() {
-            sortable_attributes.push(field.ident.clone().unwrap().to_string());
+            sortable_attributes.push(field_name.clone());
         }
     }
 
@@ -128,6 +175,53 @@ fn get_index_config_implementation(
         "with_distinct_attribute",
     );
 
+    let pagination_token = match struct_attrs.max_total_hits.as_ref().map(get_usize_literal) {
+        Some(Ok(max_total_hits)) => quote! {
+            .with_pagination(::meilisearch_sdk::settings::PaginationSetting {
+                max_total_hits: #max_total_hits,
+            })
+        },
+        Some(Err(error)) => return error.to_compile_error(),
+        None => proc_macro2::TokenStream::new(),
+    };
+    let faceting_token = match struct_attrs
+        .max_values_per_facet
+        .as_ref()
+        .map(get_usize_literal)
+    {
+        Some(Ok(max_values_per_facet)) => quote! {
+            .with_faceting(&::meilisearch_sdk::settings::FacetingSettings {
+                max_values_per_facet: #max_values_per_facet,
+            })
+        },
+        Some(Err(error)) => return error.to_compile_error(),
+        None => proc_macro2::TokenStream::new(),
+    };
+
+    let ranking_rules_token = match &struct_attrs.ranking_rules {
+        Some(ranking_rules) => {
+            for ranking_rule in &ranking_rules.args {
+                if let Err(error) = check_ranking_rule(ranking_rule, &sortable_attributes) {
+                    return error.to_compile_error();
+                }
+            }
+            let ranking_rules = &ranking_rules.args;
+            quote! {
+                .with_ranking_rules([#(#ranking_rules),*])
+            }
+        }
+        None => proc_macro2::TokenStream::new(),
+    };
+    let stop_words_token = match &struct_attrs.stop_words {
+        Some(stop_words) => {
+            let stop_words = &stop_words.args;
+            quote! {
+                .with_stop_words([#(#stop_words),*])
+            }
+        }
+        None => proc_macro2::TokenStream::new(),
+    };
+
     quote! {
         #[::meilisearch_sdk::macro_helper::async_trait(?Send)]
         impl ::meilisearch_sdk::documents::IndexConfig for #struct_ident {
@@ -140,6 +234,10 @@ fn get_index_config_implementation(
             #filterable_attr_tokens
             #searchable_attr_tokens
             #distinct_attr_token
+            #pagination_token
+            #faceting_token
+            #ranking_rules_token
+            #stop_words_token
         }
 
          async fn generate_index<Http: ::meilisearch_sdk::request::HttpClient>(client: &::meilisearch_sdk::client::Client<Http>) -> std::result::Result<::meilisearch_sdk::indexes::Index<Http>, ::meilisearch_sdk::tasks::Task> {
@@ -189,3 +287,159 @@ fn get_settings_token_for_string_for_some_string(
         }
     }
 }
+
+/// Checks a ranking rule is either a built-in rule or a custom sort on a `sortable` field.
+fn check_ranking_rule(
+    ranking_rule: &syn::LitStr,
+    sortable_attributes: &[String],
+) -> syn::Result<()> {
+    let value = ranking_rule.value();
+    match value.rsplit_once(':') {
+        Some((attribute, "asc" | "desc")) => {
+            if sortable_attributes
+                .iter()
+                .any(|sortable| sortable == attribute)
+            {
+                Ok(())
+            } else {
+                Err(syn::Error::new(
+                    ranking_rule.span(),
+                    format!("The custom ranking rule `{value}` needs the `{attribute}` field to be marked as sortable"),
+                ))
+            }
+        }
+        Some(_) => Err(syn::Error::new(
+            ranking_rule.span(),
+            "Custom ranking rules must end with `:asc` or `:desc`",
+        )),
+        None => match value.as_str() {
+            "words" | "typo" | "proximity" | "attribute" | "sort" | "exactness" => Ok(()),
+            _ => Err(syn::Error::new(
+                ranking_rule.span(),
+                format!("Unknown ranking rule `{value}`"),
+            )),
+        },
+    }
+}
+
+/// Checks the literal fits in a `usize` and strips its suffix, if any.
+fn get_usize_literal(literal: &syn::LitInt) -> syn::Result<syn::LitInt> {
+    literal.base10_parse::<usize>()?;
+    Ok(syn::LitInt::new(literal.base10_digits(), literal.span()))
+}
+
+/// Returns the `rename_all` rule of the `#[serde(..)]` container attributes, if any.
+fn get_serde_rename_all(attrs: &[syn::Attribute]) -> syn::Result<Option<syn::LitStr>> {
+    let mut rename_all = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                rename_all = parse_serde_serialize_name(&meta)?;
+            } else {
+                skip_serde_meta(&meta)?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(rename_all)
+}
+
+/// Returns the name of the field once serialized by serde, taking `rename` and `rename_all` into account.
+fn get_serialized_field_name(
+    field: &syn::Field,
+    rename_all: Option<&syn::LitStr>,
+) -> syn::Result<String> {
+    let mut rename = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = parse_serde_serialize_name(&meta)?;
+            } else {
+                skip_serde_meta(&meta)?;
+            }
+            Ok(())
+        })?;
+    }
+
+    if let Some(rename) = rename {
+        return Ok(rename.value());
+    }
+
+    let field_name = field.ident.as_ref().unwrap().unraw().to_string();
+    match rename_all {
+        Some(rule) => apply_serde_rename_rule(&field_name, rule),
+        None => Ok(field_name),
+    }
+}
+
+/// Parses both `rename = "name"` and `rename(serialize = "name")`, keeping the serialization name.
+fn parse_serde_serialize_name(
+    meta: &syn::meta::ParseNestedMeta,
+) -> syn::Result<Option<syn::LitStr>> {
+    if meta.input.peek(syn::Token![=]) {
+        return meta.value()?.parse().map(Some);
+    }
+
+    let mut serialize = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("serialize") {
+            serialize = Some(meta.value()?.parse()?);
+        } else {
+            skip_serde_meta(&meta)?;
+        }
+        Ok(())
+    })?;
+    Ok(serialize)
+}
+
+/// Consumes a serde attribute the macro doesn't care about, like `default` or `skip_serializing_if = ".."`.
+fn skip_serde_meta(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        content.parse::<proc_macro2::TokenStream>()?;
+    }
+    Ok(())
+}
+
+/// Applies a serde `rename_all` rule to a snake case field name, the same way serde does.
+fn apply_serde_rename_rule(field_name: &str, rule: &syn::LitStr) -> syn::Result<String> {
+    let pascal_case = || {
+        let mut pascal = String::new();
+        let mut capitalize = true;
+        for ch in field_name.chars() {
+            if ch == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(ch.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(ch);
+            }
+        }
+        pascal
+    };
+
+    Ok(match rule.value().as_str() {
+        "lowercase" | "snake_case" => field_name.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field_name.to_ascii_uppercase(),
+        "PascalCase" => pascal_case(),
+        "camelCase" => {
+            let pascal = pascal_case();
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => pascal,
+            }
+        }
+        "kebab-case" => field_name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field_name.to_ascii_uppercase().replace('_', "-"),
+        _ => return Err(syn::Error::new(rule.span(), "Unknown serde rename rule")),
+    })
+}
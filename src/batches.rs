@@ -0,0 +1,356 @@
+//! The `batches` module gives access to the batches in which Meilisearch groups [tasks](crate::tasks::Task).
+//!
+//! A batch is a group of tasks processed together: its stats and duration describe how long the
+//! indexing of these tasks took. Batches are only available since Meilisearch 1.13.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::{collections::HashMap, time::Duration};
+use time::OffsetDateTime;
+
+use crate::{client::Client, errors::Error, request::*, tasks::deserialize_duration};
+
+/// A group of [tasks](crate::tasks::Task) processed together by Meilisearch.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Batch {
+    pub uid: u32,
+    /// Details aggregated from the tasks of the batch, such as the number of received and indexed documents.
+    pub details: Map<String, Value>,
+    pub stats: BatchStats,
+    /// Progress of the batch while it is processing, `None` once it is finished.
+    pub progress: Option<Value>,
+    /// Time spent processing the batch, `None` while it is processing.
+    #[serde(default, deserialize_with = "deserialize_optional_duration")]
+    pub duration: Option<Duration>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+/// Number of tasks of a [`Batch`], grouped by status, type and index uid.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStats {
+    pub total_nb_tasks: usize,
+    pub status: HashMap<String, usize>,
+    pub types: HashMap<String, usize>,
+    pub index_uids: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchesResults {
+    pub results: Vec<Batch>,
+    /// Total number of batches matching the query, across all pages.
+    pub total: u64,
+    pub limit: u32,
+    /// Uid of the first batch of this page.
+    pub from: Option<u32>,
+    /// Uid to pass to [`BatchesQuery::with_from`] to fetch the next page, `None` on the last page.
+    pub next: Option<u32>,
+}
+
+fn deserialize_optional_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_duration")] Duration);
+
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(duration)| duration))
+}
+
+/// A [`BatchesQuery`] filters and paginates the batches returned by [`Client::get_batches_with`].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, batches::*};
+/// #
+/// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+/// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+/// #
+/// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+/// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+/// let batches = BatchesQuery::new(&client)
+///     .with_statuses(["succeeded"])
+///     .with_limit(10)
+///     .execute()
+///     .await
+///     .unwrap();
+/// # });
+/// ```
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchesQuery<'a, Http: HttpClient> {
+    #[serde(skip_serializing)]
+    client: &'a Client<Http>,
+    // Uids of the batches to retrieve.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uids: Option<Vec<&'a u32>>,
+    // Index uids array to only retrieve the batches containing tasks of these indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index_uids: Option<Vec<&'a str>>,
+    // Statuses array to only retrieve the batches containing tasks with these statuses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statuses: Option<Vec<&'a str>>,
+    // Types array to only retrieve the batches containing tasks of these types.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "types")]
+    task_types: Option<Vec<&'a str>>,
+    // Maximum number of batches to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+    // The first batch uid that should be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<u32>,
+}
+
+#[allow(missing_docs)]
+impl<'a, Http: HttpClient> BatchesQuery<'a, Http> {
+    #[must_use]
+    pub fn new(client: &'a Client<Http>) -> BatchesQuery<'a, Http> {
+        BatchesQuery {
+            client,
+            uids: None,
+            index_uids: None,
+            statuses: None,
+            task_types: None,
+            limit: None,
+            from: None,
+        }
+    }
+    pub fn with_uids<'b>(
+        &'b mut self,
+        uids: impl IntoIterator<Item = &'a u32>,
+    ) -> &'b mut BatchesQuery<'a, Http> {
+        self.uids = Some(uids.into_iter().collect());
+        self
+    }
+    pub fn with_index_uids<'b>(
+        &'b mut self,
+        index_uids: impl IntoIterator<Item = &'a str>,
+    ) -> &'b mut BatchesQuery<'a, Http> {
+        self.index_uids = Some(index_uids.into_iter().collect());
+        self
+    }
+    pub fn with_statuses<'b>(
+        &'b mut self,
+        statuses: impl IntoIterator<Item = &'a str>,
+    ) -> &'b mut BatchesQuery<'a, Http> {
+        self.statuses = Some(statuses.into_iter().collect());
+        self
+    }
+    pub fn with_types<'b>(
+        &'b mut self,
+        task_types: impl IntoIterator<Item = &'a str>,
+    ) -> &'b mut BatchesQuery<'a, Http> {
+        self.task_types = Some(task_types.into_iter().collect());
+        self
+    }
+    pub fn with_limit<'b>(&'b mut self, limit: u32) -> &'b mut BatchesQuery<'a, Http> {
+        self.limit = Some(limit);
+        self
+    }
+    pub fn with_from<'b>(&'b mut self, from: u32) -> &'b mut BatchesQuery<'a, Http> {
+        self.from = Some(from);
+        self
+    }
+    pub async fn execute(&'a self) -> Result<BatchesResults, Error> {
+        self.client.get_batches_with(self).await
+    }
+}
+
+/// Batch related methods.
+/// See the [batches](crate::batches) module.
+impl<Http: HttpClient> Client<Http> {
+    /// Get the most recent batches from the server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let batches = client.get_batches().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_batches(&self) -> Result<BatchesResults, Error> {
+        self.http_client
+            .request::<(), (), BatchesResults>(
+                &format!("{}/batches", self.host),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+    }
+
+    /// Get the batches matching a [`BatchesQuery`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, batches::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let mut query = BatchesQuery::new(&client);
+    /// query.with_index_uids(["get_batches_with"]);
+    ///
+    /// let batches = client.get_batches_with(&query).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_batches_with(
+        &self,
+        batches_query: &BatchesQuery<'_, Http>,
+    ) -> Result<BatchesResults, Error> {
+        self.http_client
+            .request::<&BatchesQuery<Http>, (), BatchesResults>(
+                &format!("{}/batches", self.host),
+                Method::Get {
+                    query: batches_query,
+                },
+                200,
+            )
+            .await
+    }
+
+    /// Get a [`Batch`] by its uid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # let uid = client.get_batches().await.unwrap().results[0].uid;
+    /// let batch = client.get_batch(uid).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_batch(&self, uid: u32) -> Result<Batch, Error> {
+        self.http_client
+            .request::<(), (), Batch>(
+                &format!("{}/batches/{}", self.host, uid),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexes::Index;
+    use meilisearch_test_macro::meilisearch_test;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_batch() {
+        let batch: Batch = serde_json::from_value(json!({
+            "uid": 1,
+            "progress": null,
+            "details": {
+                "receivedDocuments": 2,
+                "indexedDocuments": 2
+            },
+            "stats": {
+                "totalNbTasks": 2,
+                "status": { "succeeded": 2 },
+                "types": { "documentAdditionOrUpdate": 2 },
+                "indexUids": { "movies": 2 }
+            },
+            "duration": "PT0.250518S",
+            "startedAt": "2024-12-10T15:20:30.18182Z",
+            "finishedAt": "2024-12-10T15:20:30.432338Z"
+        }))
+        .unwrap();
+
+        assert_eq!(batch.uid, 1);
+        assert_eq!(batch.details["indexedDocuments"], json!(2));
+        assert_eq!(batch.stats.total_nb_tasks, 2);
+        assert_eq!(batch.stats.index_uids["movies"], 2);
+        assert_eq!(batch.duration, Some(Duration::from_millis(250)));
+        assert!(batch.finished_at.is_some());
+
+        let processing: Batch = serde_json::from_value(json!({
+            "uid": 2,
+            "progress": { "steps": [], "percentage": 0.0 },
+            "details": {},
+            "stats": {
+                "totalNbTasks": 1,
+                "status": { "processing": 1 },
+                "types": { "documentAdditionOrUpdate": 1 },
+                "indexUids": { "movies": 1 }
+            },
+            "duration": null,
+            "startedAt": "2024-12-10T15:20:30.18182Z",
+            "finishedAt": null
+        }))
+        .unwrap();
+
+        assert!(processing.progress.is_some());
+        assert_eq!(processing.duration, None);
+        assert_eq!(processing.finished_at, None);
+    }
+
+    #[meilisearch_test]
+    async fn test_get_batches_with_params() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let mock_server_url = s.url();
+        let client = Client::new(mock_server_url, Some("masterKey")).unwrap();
+        let path = "/batches?uids=1&indexUids=movies,test&statuses=succeeded&types=documentDeletion&limit=2&from=3";
+
+        let mock_res = s.mock("GET", path).with_status(200).create_async().await;
+
+        let _ = BatchesQuery::new(&client)
+            .with_uids([&1])
+            .with_index_uids(["movies", "test"])
+            .with_statuses(["succeeded"])
+            .with_types(["documentDeletion"])
+            .with_limit(2)
+            .with_from(3)
+            .execute()
+            .await;
+
+        mock_res.assert_async().await;
+
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_batches(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(&[json!({ "id": 0 })], Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let batches = BatchesQuery::new(&client)
+            .with_index_uids([index.uid.as_str()])
+            .with_types(["documentAdditionOrUpdate"])
+            .execute()
+            .await?;
+        assert_eq!(batches.results.len(), 1);
+
+        let batch = client.get_batch(batches.results[0].uid).await?;
+        assert_eq!(batch.uid, batches.results[0].uid);
+        assert_eq!(batch.stats.index_uids[index.uid.as_str()], 1);
+        assert!(batch.duration.is_some());
+        assert!(batch.finished_at.is_some());
+
+        assert!(client.get_batches().await?.total > 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,368 @@
+//! A blocking wrapper around the asynchronous [`Client`], for scripts and CLIs that don't run an async runtime.
+//!
+//! Every method has the same name as its asynchronous counterpart and blocks the current thread until the request completes.
+//! The tokio runtime driving the requests is created once by [`BlockingClient::new`] and shared by every [`BlockingIndex`] it creates.
+//!
+//! These methods must not be called from within an async runtime, they will panic.
+//!
+//! # Example
+//!
+//! ```
+//! # use serde::{Serialize, Deserialize};
+//! # use meilisearch_sdk::{blocking::*, search::*};
+//! #
+//! # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+//! # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+//! #
+//! #[derive(Serialize, Deserialize, Debug)]
+//! struct Movie {
+//!     id: String,
+//!     title: String,
+//! }
+//!
+//! let client = BlockingClient::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+//! let movies = client.index("blocking_movies");
+//!
+//! let task = movies
+//!     .add_documents(&[Movie { id: "1".to_string(), title: "Interstellar".to_string() }], Some("id"))
+//!     .unwrap();
+//! client.wait_for_task(task, None, None).unwrap();
+//!
+//! let query = SearchQuery::new(movies.as_async()).with_query("interstellar").build();
+//! let results = movies.execute_query::<Movie>(&query).unwrap();
+//!
+//! assert_eq!(results.hits[0].result.title, "Interstellar");
+//! # client.wait_for_task(movies.delete().unwrap(), None, None).unwrap();
+//! ```
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::runtime::Runtime;
+
+use crate::{
+    client::{Client, ClientStats, Health, Version},
+    documents::DocumentsResults,
+    errors::Error,
+    indexes::{Index, IndexStats},
+    reqwest::ReqwestClient,
+    search::{SearchQuery, SearchResults},
+    settings::Settings,
+    task_info::TaskInfo,
+    tasks::{Task, TasksResults},
+};
+
+/// A blocking version of [`Client`].
+#[derive(Debug, Clone)]
+pub struct BlockingClient {
+    inner: Client<ReqwestClient>,
+    runtime: Arc<Runtime>,
+}
+
+/// A blocking version of [`Index`], created by a [`BlockingClient`].
+#[derive(Debug, Clone)]
+pub struct BlockingIndex {
+    inner: Index<ReqwestClient>,
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingClient {
+    /// Create a blocking client, along with the runtime used to run its requests.
+    pub fn new(
+        host: impl Into<String>,
+        api_key: Option<impl Into<String>>,
+    ) -> Result<BlockingClient, Error> {
+        BlockingClient::from_client(Client::new(host, api_key)?)
+    }
+
+    /// Wrap an existing asynchronous [`Client`].
+    pub fn from_client(client: Client<ReqwestClient>) -> Result<BlockingClient, Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| Error::Other(Box::new(error)))?;
+
+        Ok(BlockingClient {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The asynchronous [`Client`] wrapped by this client.
+    #[must_use]
+    pub fn as_async(&self) -> &Client<ReqwestClient> {
+        &self.inner
+    }
+
+    /// Block on any future, for the methods that don't have a blocking version.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::blocking::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = BlockingClient::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let keys = client.block_on(client.as_async().get_keys()).unwrap();
+    /// ```
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Create a [`BlockingIndex`] without checking it exists, see [`Client::index`].
+    #[must_use]
+    pub fn index(&self, uid: impl Into<String>) -> BlockingIndex {
+        BlockingIndex {
+            inner: self.inner.index(uid),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// See [`Client::get_index`].
+    pub fn get_index(&self, uid: impl AsRef<str>) -> Result<BlockingIndex, Error> {
+        let index = self.block_on(self.inner.get_index(uid))?;
+        Ok(BlockingIndex {
+            inner: index,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// See [`Client::create_index`].
+    pub fn create_index(
+        &self,
+        uid: impl AsRef<str>,
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.block_on(self.inner.create_index(uid, primary_key))
+    }
+
+    /// See [`Client::delete_index`].
+    pub fn delete_index(&self, uid: impl AsRef<str>) -> Result<TaskInfo, Error> {
+        self.block_on(self.inner.delete_index(uid))
+    }
+
+    /// See [`Client::get_stats`].
+    pub fn get_stats(&self) -> Result<ClientStats, Error> {
+        self.block_on(self.inner.get_stats())
+    }
+
+    /// See [`Client::health`].
+    pub fn health(&self) -> Result<Health, Error> {
+        self.block_on(self.inner.health())
+    }
+
+    /// See [`Client::is_healthy`].
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.block_on(self.inner.is_healthy())
+    }
+
+    /// See [`Client::get_version`].
+    pub fn get_version(&self) -> Result<Version, Error> {
+        self.block_on(self.inner.get_version())
+    }
+
+    /// See [`Client::get_task`].
+    pub fn get_task(&self, task_id: impl AsRef<u32>) -> Result<Task, Error> {
+        self.block_on(self.inner.get_task(task_id))
+    }
+
+    /// See [`Client::get_tasks`].
+    pub fn get_tasks(&self) -> Result<TasksResults, Error> {
+        self.block_on(self.inner.get_tasks())
+    }
+
+    /// See [`Client::wait_for_task`].
+    pub fn wait_for_task(
+        &self,
+        task_id: impl AsRef<u32>,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        self.block_on(self.inner.wait_for_task(task_id, interval, timeout))
+    }
+
+    /// See [`Client::wait_for_task_uid`].
+    pub fn wait_for_task_uid(
+        &self,
+        task_uid: u32,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        self.block_on(self.inner.wait_for_task_uid(task_uid, interval, timeout))
+    }
+}
+
+impl BlockingIndex {
+    /// The asynchronous [`Index`] wrapped by this index, to build queries with.
+    #[must_use]
+    pub fn as_async(&self) -> &Index<ReqwestClient> {
+        &self.inner
+    }
+
+    /// The uid of the index.
+    #[must_use]
+    pub fn uid(&self) -> &str {
+        &self.inner.uid
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// See [`Index::delete`].
+    pub fn delete(self) -> Result<TaskInfo, Error> {
+        self.runtime.block_on(self.inner.delete())
+    }
+
+    /// See [`Index::execute_query`].
+    pub fn execute_query<T: 'static + DeserializeOwned + Send + Sync>(
+        &self,
+        query: &SearchQuery<'_, ReqwestClient>,
+    ) -> Result<SearchResults<T>, Error> {
+        self.block_on(self.inner.execute_query(query))
+    }
+
+    /// See [`Index::get_document`].
+    pub fn get_document<T: 'static + DeserializeOwned + Send + Sync>(
+        &self,
+        document_id: &str,
+    ) -> Result<T, Error> {
+        self.block_on(self.inner.get_document(document_id))
+    }
+
+    /// See [`Index::get_documents`].
+    pub fn get_documents<T: DeserializeOwned + 'static + Send + Sync>(
+        &self,
+    ) -> Result<DocumentsResults<T>, Error> {
+        self.block_on(self.inner.get_documents())
+    }
+
+    /// See [`Index::add_or_replace`].
+    pub fn add_or_replace<T: Serialize + Send + Sync>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.block_on(self.inner.add_or_replace(documents, primary_key))
+    }
+
+    /// Alias for [`BlockingIndex::add_or_replace`].
+    pub fn add_documents<T: Serialize + Send + Sync>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.add_or_replace(documents, primary_key)
+    }
+
+    /// See [`Index::add_or_update`].
+    pub fn add_or_update<T: Serialize + Send + Sync>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.block_on(self.inner.add_or_update(documents, primary_key))
+    }
+
+    /// See [`Index::delete_document`].
+    pub fn delete_document<T: std::fmt::Display>(&self, uid: T) -> Result<TaskInfo, Error> {
+        self.block_on(self.inner.delete_document(uid))
+    }
+
+    /// See [`Index::delete_all_documents`].
+    pub fn delete_all_documents(&self) -> Result<TaskInfo, Error> {
+        self.block_on(self.inner.delete_all_documents())
+    }
+
+    /// See [`Index::get_settings`].
+    pub fn get_settings(&self) -> Result<Settings, Error> {
+        self.block_on(self.inner.get_settings())
+    }
+
+    /// See [`Index::set_settings`].
+    pub fn set_settings(&self, settings: &Settings) -> Result<TaskInfo, Error> {
+        self.block_on(self.inner.set_settings(settings))
+    }
+
+    /// See [`Index::get_stats`].
+    pub fn get_stats(&self) -> Result<IndexStats, Error> {
+        self.block_on(self.inner.get_stats())
+    }
+
+    /// See [`Index::get_task`].
+    pub fn get_task(&self, uid: impl AsRef<u32>) -> Result<Task, Error> {
+        self.block_on(self.inner.get_task(uid))
+    }
+
+    /// See [`Index::wait_for_task`].
+    pub fn wait_for_task(
+        &self,
+        task_id: impl AsRef<u32>,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        self.block_on(self.inner.wait_for_task(task_id, interval, timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Document {
+        id: usize,
+        value: String,
+    }
+
+    fn blocking_client() -> BlockingClient {
+        let meilisearch_url = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+        let meilisearch_api_key = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+        BlockingClient::new(meilisearch_url, Some(meilisearch_api_key)).unwrap()
+    }
+
+    #[test]
+    fn test_blocking_documents_round_trip() -> Result<(), Error> {
+        let client = blocking_client();
+        let index = client.index("test_blocking_documents_round_trip");
+
+        let task = index.add_documents(
+            &[Document {
+                id: 1,
+                value: "blocking".to_string(),
+            }],
+            Some("id"),
+        )?;
+        assert!(client.wait_for_task(task, None, None)?.is_success());
+
+        let document: Document = index.get_document("1")?;
+        assert_eq!(
+            document,
+            Document {
+                id: 1,
+                value: "blocking".to_string(),
+            }
+        );
+
+        let query = SearchQuery::new(index.as_async())
+            .with_query("blocking")
+            .build();
+        let results = index.execute_query::<Document>(&query)?;
+        assert_eq!(results.hits.len(), 1);
+
+        let task = index.delete()?;
+        client.wait_for_task(task, None, None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_indexes_share_the_runtime() {
+        let client = blocking_client();
+        let index = client.index("test_blocking_indexes_share_the_runtime");
+
+        assert!(Arc::ptr_eq(&client.runtime, &index.runtime));
+        assert!(client.is_healthy());
+    }
+}
@@ -0,0 +1,263 @@
+//! The `chats` module gives access to the experimental [conversational search](https://www.meilisearch.com/docs/reference/api/chats) of Meilisearch.
+//!
+//! - Chat completions are answered by the LLM configured in a chat workspace, using the indexes of the instance as context.
+//!
+//! - The `chatCompletions` [experimental feature](crate::features::ExperimentalFeatures) must be enabled.
+//! Otherwise Meilisearch answers with an [`ErrorCode::FeatureNotEnabled`](crate::errors::ErrorCode::FeatureNotEnabled) error.
+//!
+//! - Responses are buffered: the SDK waits for the whole answer before returning it.
+//!
+//! # Example
+//!
+//! ```
+//! # use meilisearch_sdk::{client::*, chats::*, features::ExperimentalFeatures};
+//! # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+//! #
+//! # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+//! # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+//! #
+//! # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+//! let mut features = ExperimentalFeatures::new(&client);
+//! features.set_chat_completions(true);
+//! features.update().await.unwrap();
+//!
+//! let workspaces = client.get_chat_workspaces().await.unwrap();
+//! # });
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, errors::Error, request::*};
+
+/// The author of a [`ChatMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    System,
+    User,
+    Assistant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    #[must_use]
+    pub fn new(role: ChatRole, content: impl Into<String>) -> ChatMessage {
+        ChatMessage {
+            role,
+            content: content.into(),
+        }
+    }
+}
+
+/// A request sent to [`Client::chat_completions`], following the OpenAI chat completions format.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::chats::*;
+/// let mut request = ChatCompletionRequest::new("gpt-4o-mini");
+/// request
+///     .with_message(ChatMessage::new(ChatRole::System, "You are a movie expert."))
+///     .with_message(ChatMessage::new(ChatRole::User, "Which movies are about space?"));
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatCompletionRequest {
+    pub model: String,
+    pub messages: Vec<ChatMessage>,
+    stream: bool,
+}
+
+impl ChatCompletionRequest {
+    #[must_use]
+    pub fn new(model: impl Into<String>) -> ChatCompletionRequest {
+        ChatCompletionRequest {
+            model: model.into(),
+            messages: Vec::new(),
+            stream: false,
+        }
+    }
+
+    pub fn with_message(&mut self, message: ChatMessage) -> &mut ChatCompletionRequest {
+        self.messages.push(message);
+        self
+    }
+}
+
+impl AsRef<ChatCompletionRequest> for ChatCompletionRequest {
+    fn as_ref(&self) -> &ChatCompletionRequest {
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatCompletionResponse {
+    pub id: String,
+    pub model: Option<String>,
+    pub created: Option<u64>,
+    pub choices: Vec<ChatCompletionChoice>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatCompletionChoice {
+    pub index: usize,
+    pub message: ChatMessage,
+    pub finish_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatWorkspace {
+    pub uid: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatWorkspacesResults {
+    pub results: Vec<ChatWorkspace>,
+    pub offset: u32,
+    pub limit: u32,
+    pub total: u32,
+}
+
+/// Chat related methods.
+/// See the [chats](crate::chats) module.
+impl<Http: HttpClient> Client<Http> {
+    /// List the chat workspaces of the instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, features::ExperimentalFeatures};
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # ExperimentalFeatures::new(&client).set_chat_completions(true).update().await.unwrap();
+    /// let workspaces = client.get_chat_workspaces().await.unwrap();
+    ///
+    /// assert_eq!(workspaces.limit, 20);
+    /// # });
+    /// ```
+    pub async fn get_chat_workspaces(&self) -> Result<ChatWorkspacesResults, Error> {
+        self.http_client
+            .request::<(), (), ChatWorkspacesResults>(
+                &format!("{}/chats", self.host),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+    }
+
+    /// Ask the LLM of a chat workspace to answer a conversation, using the indexes of the instance as context.
+    ///
+    /// The workspace must have been configured with an LLM provider beforehand.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, chats::*};
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let mut request = ChatCompletionRequest::new("gpt-4o-mini");
+    /// request.with_message(ChatMessage::new(ChatRole::User, "Which movies are about space?"));
+    ///
+    /// let response = client.chat_completions("movies", &request).await.unwrap();
+    ///
+    /// println!("{}", response.choices[0].message.content);
+    /// # });
+    /// ```
+    pub async fn chat_completions(
+        &self,
+        workspace: impl AsRef<str>,
+        request: impl AsRef<ChatCompletionRequest>,
+    ) -> Result<ChatCompletionResponse, Error> {
+        self.http_client
+            .request::<(), &ChatCompletionRequest, ChatCompletionResponse>(
+                &format!(
+                    "{}/chats/{}/chat/completions",
+                    self.host,
+                    workspace.as_ref()
+                ),
+                Method::Post {
+                    query: (),
+                    body: request.as_ref(),
+                },
+                200,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::{ErrorCode, MeilisearchError},
+        features::ExperimentalFeatures,
+    };
+    use meilisearch_test_macro::meilisearch_test;
+    use serde_json::json;
+
+    #[test]
+    fn test_chat_completion_request_serialization() {
+        let mut request = ChatCompletionRequest::new("gpt-4o-mini");
+        request.with_message(ChatMessage::new(ChatRole::User, "Hello"));
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "model": "gpt-4o-mini",
+                "messages": [{ "role": "user", "content": "Hello" }],
+                "stream": false,
+            })
+        );
+    }
+
+    #[meilisearch_test]
+    async fn test_chat_completions_feature(client: Client) -> Result<(), Error> {
+        // The experimental features are shared by every test running against the server
+        let mut features = ExperimentalFeatures::new(&client);
+        let enabled = features.get().await?.chat_completions;
+
+        let result = async {
+            features.set_chat_completions(false);
+            features.update().await?;
+
+            let mut request = ChatCompletionRequest::new("gpt-4o-mini");
+            request.with_message(ChatMessage::new(ChatRole::User, "Hello"));
+            let error = client
+                .chat_completions("test_chat_completions_feature", &request)
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                error,
+                Error::Meilisearch(MeilisearchError {
+                    error_code: ErrorCode::FeatureNotEnabled,
+                    ..
+                })
+            ));
+
+            features.set_chat_completions(true);
+            features.update().await?;
+
+            let workspaces = client.get_chat_workspaces().await?;
+            assert_eq!(workspaces.offset, 0);
+            Ok(())
+        }
+        .await;
+
+        features.set_chat_completions(enabled);
+        features.update().await?;
+        result
+    }
+}
@@ -1,3 +1,4 @@
+use futures::{stream, Stream, TryStreamExt};
 use serde::de::Error as SerdeError;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -5,13 +6,15 @@ use std::{collections::HashMap, time::Duration};
 use time::OffsetDateTime;
 
 use crate::{
+    batches::{Batch, BatchesQuery},
     errors::*,
     indexes::*,
     key::{Key, KeyBuilder, KeyUpdater, KeysQuery, KeysResults},
     request::*,
     search::*,
     task_info::TaskInfo,
-    tasks::{Task, TasksCancelQuery, TasksDeleteQuery, TasksResults, TasksSearchQuery},
+    tasks::{Backoff, Task, TasksCancelQuery, TasksDeleteQuery, TasksResults, TasksSearchQuery},
+    tenant_tokens::SearchRules,
     utils::async_sleep,
     DefaultHttpClient,
 };
@@ -24,17 +27,35 @@ pub struct Client<Http: HttpClient = DefaultHttpClient> {
     pub(crate) http_client: Http,
 }
 
+/// A pair of [Indexes](Index) to swap with [`Client::swap_indexes`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SwapIndexes {
     pub indexes: (String, String),
 }
 
-#[cfg(feature = "reqwest")]
+impl SwapIndexes {
+    #[must_use]
+    pub fn new(first: impl Into<String>, second: impl Into<String>) -> SwapIndexes {
+        SwapIndexes {
+            indexes: (first.into(), second.into()),
+        }
+    }
+}
+
+impl<A: Into<String>, B: Into<String>> From<(A, B)> for SwapIndexes {
+    fn from((first, second): (A, B)) -> SwapIndexes {
+        SwapIndexes::new(first, second)
+    }
+}
+
+#[cfg(any(feature = "reqwest", all(feature = "wasm", target_arch = "wasm32")))]
 impl Client {
     /// Create a client using the specified server.
     ///
     /// Don't put a '/' at the end of the host.
     ///
+    /// The client holds a pooled HTTP connection: build it once and clone it where needed instead of creating a new one for every request.
+    ///
     /// In production mode, see [the documentation about authentication](https://www.meilisearch.com/docs/learn/security/master_api_keys#authentication).
     ///
     /// # Example
@@ -52,7 +73,7 @@ impl Client {
         api_key: Option<impl Into<String>>,
     ) -> Result<Client, Error> {
         let api_key = api_key.map(|key| key.into());
-        let http_client = crate::reqwest::ReqwestClient::new(api_key.as_deref())?;
+        let http_client = DefaultHttpClient::new(api_key.as_deref())?;
 
         Ok(Client {
             host: host.into(),
@@ -128,6 +149,21 @@ impl<Http: HttpClient> Client<Http> {
             .await
     }
 
+    pub async fn execute_federated_multi_search_query<
+        T: 'static + DeserializeOwned + Send + Sync,
+    >(
+        &self,
+        body: &MultiSearchQuery<'_, '_, Http>,
+    ) -> Result<FederatedSearchResults<T>, Error> {
+        self.http_client
+            .request::<(), &MultiSearchQuery<Http>, FederatedSearchResults<T>>(
+                &format!("{}/multi-search", &self.host),
+                Method::Post { body, query: () },
+                200,
+            )
+            .await
+    }
+
     /// Make multiple search requests.
     ///
     /// # Example
@@ -171,7 +207,7 @@ impl<Http: HttpClient> Client<Http> {
     /// # });
     /// ```
     #[must_use]
-    pub fn multi_search(&self) -> MultiSearchQuery<Http> {
+    pub fn multi_search(&self) -> MultiSearchQuery<'_, '_, Http> {
         MultiSearchQuery::new(self)
     }
 
@@ -354,6 +390,96 @@ impl<Http: HttpClient> Client<Http> {
         Ok(idx)
     }
 
+    /// Get an [Index], or `None` if it doesn't exist.
+    ///
+    /// Unlike [`Client::get_index`], a missing index isn't an error, any other error is still returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = match client.try_get_index("try_get_index").await.unwrap() {
+    ///     Some(index) => index,
+    ///     None => client
+    ///         .create_index("try_get_index", None)
+    ///         .await
+    ///         .unwrap()
+    ///         .wait_for_completion(&client, None, None)
+    ///         .await
+    ///         .unwrap()
+    ///         .try_make_index(&client)
+    ///         .unwrap(),
+    /// };
+    ///
+    /// assert_eq!(index.as_ref(), "try_get_index");
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn try_get_index(&self, uid: impl AsRef<str>) -> Result<Option<Index<Http>>, Error> {
+        match self.get_index(uid).await {
+            Ok(index) => Ok(Some(index)),
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            })) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Check whether an [Index] exists, without building it.
+    ///
+    /// A missing index returns `Ok(false)`, any other error is still returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// if !client.index_exists("index_exists").await.unwrap() {
+    ///     client
+    ///         .create_index("index_exists", None)
+    ///         .await
+    ///         .unwrap()
+    ///         .wait_for_completion(&client, None, None)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert!(client.index_exists("index_exists").await.unwrap());
+    /// # client.index("index_exists").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn index_exists(&self, uid: impl AsRef<str>) -> Result<bool, Error> {
+        let response = self
+            .http_client
+            .request::<(), (), serde::de::IgnoredAny>(
+                &format!("{}/indexes/{}", self.host, uid.as_ref()),
+                Method::Get { query: () },
+                200,
+            )
+            .await;
+
+        match response {
+            Ok(_) => Ok(true),
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            })) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
     /// Get a raw JSON [Index], this index should already exist.
     ///
     /// If you use it directly from an [Index], you can use the method [`Index::fetch_info`], which is the equivalent method from an index.
@@ -496,12 +622,7 @@ impl<Http: HttpClient> Client<Http> {
     /// task_index_2.wait_for_completion(&client, None, None).await.unwrap();
     ///
     /// let task = client
-    ///     .swap_indexes([&SwapIndexes {
-    ///         indexes: (
-    ///             "swap_index_1".to_string(),
-    ///             "swap_index_2".to_string(),
-    ///         ),
-    ///     }])
+    ///     .swap_indexes([&SwapIndexes::new("swap_index_1", "swap_index_2")])
     ///     .await
     ///     .unwrap();
     ///
@@ -550,6 +671,37 @@ impl<Http: HttpClient> Client<Http> {
             .await
     }
 
+    /// Get stats of a single index, without fetching the [Index] first.
+    ///
+    /// Same as [`Index::get_stats`], it is the canonical way to check the indexing progress of an index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("client_get_index_stats", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let stats = client.get_index_stats("client_get_index_stats").await.unwrap();
+    ///
+    /// assert_eq!(stats.number_of_documents, 0);
+    /// # client.index("client_get_index_stats").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_index_stats(&self, uid: impl AsRef<str>) -> Result<IndexStats, Error> {
+        self.http_client
+            .request::<(), (), IndexStats>(
+                &format!("{}/indexes/{}/stats", self.host, uid.as_ref()),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+    }
+
     /// Get health of Meilisearch server.
     ///
     /// # Example
@@ -577,7 +729,9 @@ impl<Http: HttpClient> Client<Http> {
             .await
     }
 
-    /// Get health of Meilisearch server.
+    /// Check whether the Meilisearch server is available.
+    ///
+    /// Any error, including an unreachable server, is reported as `false`, which makes it suitable for readiness probes.
     ///
     /// # Example
     ///
@@ -736,6 +890,63 @@ impl<Http: HttpClient> Client<Http> {
             .await
     }
 
+    /// Delete every API [Key] matching the `predicate`, and return the number of deleted keys.
+    ///
+    /// All the keys are fetched before deleting the matching ones one by one. If a deletion fails, the error is returned
+    /// and the remaining keys are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::Error, key::KeyBuilder};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let mut key = KeyBuilder::new();
+    /// key.with_name("delete_keys_where_rotated");
+    /// client.create_key(key).await.unwrap();
+    ///
+    /// let deleted = client
+    ///     .delete_keys_where(|key| {
+    ///         key.name
+    ///             .as_deref()
+    ///             .map_or(false, |name| name.starts_with("delete_keys_where_"))
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(deleted, 1);
+    /// # });
+    /// ```
+    pub async fn delete_keys_where(
+        &self,
+        predicate: impl Fn(&Key) -> bool,
+    ) -> Result<usize, Error> {
+        let mut matching = Vec::new();
+        let mut offset = 0;
+        loop {
+            let keys = self
+                .get_keys_with(KeysQuery::new().with_offset(offset).with_limit(100))
+                .await?;
+            let fetched = keys.results.len();
+            matching.extend(keys.results.into_iter().filter(|key| predicate(key)));
+
+            offset += fetched;
+            if fetched == 0 || offset >= keys.total as usize {
+                break;
+            }
+        }
+
+        for key in &matching {
+            self.delete_key(&key.uid).await?;
+        }
+
+        Ok(matching.len())
+    }
+
     /// Create an API [Key] in Meilisearch.
     ///
     /// See also [`Client::update_key`], [`Client::delete_key`], [`Client::get_key`], and the [meilisearch documentation](https://www.meilisearch.com/docs/reference/api/keys#create-a-key).
@@ -886,6 +1097,43 @@ impl<Http: HttpClient> Client<Http> {
         task_id: impl AsRef<u32>,
         interval: Option<Duration>,
         timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        self.wait_for_task_uid(*task_id.as_ref(), interval, timeout)
+            .await
+    }
+
+    /// Same as [`Client::wait_for_task`], but from the bare uid of the task.
+    ///
+    /// Useful to resume waiting for a task whose uid was stored, for example across restarts of the process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let task_uid: u32 = client
+    ///     .create_index("client_wait_for_task_uid", None)
+    ///     .await
+    ///     .unwrap()
+    ///     .task_uid;
+    ///
+    /// // ...later, with only the uid at hand
+    /// let status = client.wait_for_task_uid(task_uid, None, None).await.unwrap();
+    ///
+    /// assert!(matches!(status, Task::Succeeded { .. }));
+    /// # client.index("client_wait_for_task_uid").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn wait_for_task_uid(
+        &self,
+        task_uid: u32,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
     ) -> Result<Task, Error> {
         let interval = interval.unwrap_or_else(|| Duration::from_millis(50));
         let timeout = timeout.unwrap_or_else(|| Duration::from_millis(5000));
@@ -894,11 +1142,11 @@ impl<Http: HttpClient> Client<Http> {
         let mut task_result: Result<Task, Error>;
 
         while timeout > elapsed_time {
-            task_result = self.get_task(&task_id).await;
+            task_result = self.get_task_by_uid(task_uid).await;
             match task_result {
                 Ok(status) => match status {
-                    Task::Failed { .. } | Task::Succeeded { .. } => {
-                        return self.get_task(task_id).await;
+                    Task::Failed { .. } | Task::Succeeded { .. } | Task::Canceled { .. } => {
+                        return self.get_task_by_uid(task_uid).await;
                     }
                     Task::Enqueued { .. } | Task::Processing { .. } => {
                         elapsed_time += interval;
@@ -912,6 +1160,69 @@ impl<Http: HttpClient> Client<Http> {
         Err(Error::Timeout)
     }
 
+    /// Wait until Meilisearch processes a [Task], polling less and less often following a [`Backoff`].
+    ///
+    /// Unlike [`Client::wait_for_task`], long tasks don't flood the server with requests.
+    /// If the task is still pending when the backoff budget is exhausted, an [`Error::TaskWaitTimeout`] is returned.
+    /// A `multiplier` that is NaN or infinite is rejected with an [`Error::InvalidBackoffMultiplier`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, tasks::*};
+    /// # use std::time::Duration;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let task = client.create_index("client_wait_for_task_with_backoff", None).await.unwrap();
+    ///
+    /// let backoff = Backoff {
+    ///     max_interval: Duration::from_secs(1),
+    ///     ..Backoff::default()
+    /// };
+    /// let status = client.wait_for_task_with_backoff(task, &backoff).await.unwrap();
+    ///
+    /// assert!(matches!(status, Task::Succeeded { .. }));
+    /// # client.index("client_wait_for_task_with_backoff").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn wait_for_task_with_backoff(
+        &self,
+        task_id: impl AsRef<u32>,
+        backoff: &Backoff,
+    ) -> Result<Task, Error> {
+        if !backoff.multiplier.is_finite() {
+            return Err(Error::InvalidBackoffMultiplier(backoff.multiplier));
+        }
+
+        let mut interval = backoff.initial_interval;
+        let mut elapsed = Duration::new(0, 0);
+        let mut attempts = 0;
+
+        loop {
+            let task = self.get_task(&task_id).await?;
+            attempts += 1;
+            if !task.is_pending() {
+                return Ok(task);
+            }
+
+            let retries_exhausted = backoff
+                .max_retries
+                .is_some_and(|max_retries| attempts > max_retries);
+            if retries_exhausted || elapsed >= backoff.max_elapsed {
+                return Err(Error::TaskWaitTimeout { attempts, elapsed });
+            }
+
+            let sleep = interval.min(backoff.max_elapsed - elapsed);
+            async_sleep(sleep).await;
+            elapsed += sleep;
+            interval = backoff.next_interval(interval);
+        }
+    }
+
     /// Get a task from the server given a task id.
     ///
     /// # Example
@@ -932,9 +1243,13 @@ impl<Http: HttpClient> Client<Http> {
     /// # });
     /// ```
     pub async fn get_task(&self, task_id: impl AsRef<u32>) -> Result<Task, Error> {
+        self.get_task_by_uid(*task_id.as_ref()).await
+    }
+
+    async fn get_task_by_uid(&self, task_uid: u32) -> Result<Task, Error> {
         self.http_client
             .request::<(), (), Task>(
-                &format!("{}/tasks/{}", self.host, task_id.as_ref()),
+                &format!("{}/tasks/{}", self.host, task_uid),
                 Method::Get { query: () },
                 200,
             )
@@ -975,6 +1290,116 @@ impl<Http: HttpClient> Client<Http> {
         Ok(tasks)
     }
 
+    /// Stream all the [Tasks](Task) matching the query, following the `next` cursor of each page until the last one.
+    ///
+    /// The pages are fetched lazily, with the `limit` of the query as page size (20 by default), from the most recent task.
+    /// If a request fails, the error is yielded by the stream, which then ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # use futures::TryStreamExt;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let mut query = TasksSearchQuery::new(&client);
+    /// query.with_statuses(["failed"]).with_limit(100);
+    ///
+    /// let failed_tasks: Vec<Task> = client.get_tasks_stream(query).try_collect().await.unwrap();
+    /// assert!(failed_tasks.iter().all(|task| task.is_failure()));
+    /// # });
+    /// ```
+    pub fn get_tasks_stream<'a>(
+        &'a self,
+        tasks_query: TasksSearchQuery<'a, Http>,
+    ) -> impl Stream<Item = Result<Task, Error>> + 'a {
+        stream::try_unfold((tasks_query, false), move |(mut query, done)| async move {
+            if done {
+                return Ok(None);
+            }
+
+            let tasks = self.get_tasks_with(&query).await?;
+            let done = match tasks.next {
+                Some(next) if !tasks.results.is_empty() => {
+                    query.with_from(next);
+                    false
+                }
+                _ => true,
+            };
+
+            let tasks = tasks.results.into_iter().map(Ok::<Task, Error>);
+            Ok::<_, Error>(Some((stream::iter(tasks), (query, done))))
+        })
+        .try_flatten()
+    }
+
+    /// Compute the document indexing throughput of the server since a given date.
+    ///
+    /// The report is built from the succeeded [batches](crate::batches::Batch) of `documentAdditionOrUpdate` tasks started after `since`.
+    /// Batches are only available since Meilisearch 1.13.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use time::OffsetDateTime;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let since = OffsetDateTime::now_utc() - time::Duration::HOUR;
+    /// let report = client.indexing_throughput(since).await.unwrap();
+    ///
+    /// println!(
+    ///     "{} documents indexed at {:.1} documents/s",
+    ///     report.indexed_documents,
+    ///     report.documents_per_second()
+    /// );
+    /// # });
+    /// ```
+    pub async fn indexing_throughput(
+        &self,
+        since: OffsetDateTime,
+    ) -> Result<ThroughputReport, Error> {
+        let mut batches = Vec::new();
+        let mut from = None;
+
+        loop {
+            let mut query = BatchesQuery::new(self);
+            query
+                .with_types(["documentAdditionOrUpdate"])
+                .with_statuses(["succeeded"])
+                .with_limit(1000);
+            if let Some(from) = from {
+                query.with_from(from);
+            }
+
+            let results = self.get_batches_with(&query).await?;
+            // batches are returned from the most recent to the oldest
+            let mut reached_since = false;
+            for batch in results.results {
+                if batch.started_at < since {
+                    reached_since = true;
+                    break;
+                }
+                batches.push(batch);
+            }
+
+            match results.next {
+                Some(next) if !reached_since => from = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(ThroughputReport::from_batches(&batches))
+    }
+
     /// Cancel tasks with filters [`TasksCancelQuery`].
     ///
     /// # Example
@@ -1078,10 +1503,12 @@ impl<Http: HttpClient> Client<Http> {
 
     /// Generates a new tenant token.
     ///
+    /// The [`SearchRules`] restrict the indexes and documents the token can search.
+    ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::client::Client;
+    /// # use meilisearch_sdk::{client::Client, tenant_tokens::SearchRules};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
@@ -1089,16 +1516,15 @@ impl<Http: HttpClient> Client<Http> {
     /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
     /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
     /// let api_key_uid = "76cf8b87-fd12-4688-ad34-260d930ca4f4".to_string();
-    /// let token = client.generate_tenant_token(api_key_uid, serde_json::json!(["*"]), None, None).unwrap();
+    /// let token = client.generate_tenant_token(api_key_uid, SearchRules::all(), None, None).unwrap();
     ///
     /// let client = Client::new(MEILISEARCH_URL, Some(token)).unwrap();
     /// # });
     /// ```
-    #[cfg(not(target_arch = "wasm32"))]
     pub fn generate_tenant_token(
         &self,
         api_key_uid: String,
-        search_rules: Value,
+        search_rules: impl Into<SearchRules>,
         api_key: Option<&str>,
         expires_at: Option<OffsetDateTime>,
     ) -> Result<String, Error> {
@@ -1111,19 +1537,77 @@ impl<Http: HttpClient> Client<Http> {
             }
         };
 
-        crate::tenant_tokens::generate_tenant_token(api_key_uid, search_rules, api_key, expires_at)
+        crate::tenant_tokens::generate_tenant_token(
+            api_key_uid,
+            search_rules.into(),
+            api_key,
+            expires_at,
+        )
     }
 }
 
+/// Instance-wide statistics returned by [`Client::get_stats`].
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClientStats {
+    /// Size of the database, in bytes.
     pub database_size: usize,
+    /// When the last update was made to the database, `None` if no update was ever made.
     #[serde(with = "time::serde::rfc3339::option")]
     pub last_update: Option<OffsetDateTime>,
+    /// Statistics of each index, by index uid.
     pub indexes: HashMap<String, IndexStats>,
 }
 
+/// Document indexing throughput computed by [`Client::indexing_throughput`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThroughputReport {
+    /// Number of batches that indexed documents.
+    pub batches: usize,
+    /// Number of documents indexed by these batches.
+    pub indexed_documents: usize,
+    /// Time spent processing these batches.
+    pub indexing_duration: Duration,
+}
+
+impl ThroughputReport {
+    pub(crate) fn from_batches(batches: &[Batch]) -> ThroughputReport {
+        let mut report = ThroughputReport::default();
+
+        for batch in batches {
+            report.batches += 1;
+            report.indexed_documents += batch
+                .details
+                .get("indexedDocuments")
+                .and_then(Value::as_u64)
+                .map_or(0, |indexed| indexed as usize);
+            report.indexing_duration += batch.duration.unwrap_or_default();
+        }
+
+        report
+    }
+
+    /// Average number of documents indexed per second.
+    #[must_use]
+    pub fn documents_per_second(&self) -> f64 {
+        if self.indexing_duration.is_zero() {
+            return 0.0;
+        }
+        self.indexed_documents as f64 / self.indexing_duration.as_secs_f64()
+    }
+
+    /// Average time spent processing a batch, `None` if no batch was processed.
+    #[must_use]
+    pub fn average_batch_duration(&self) -> Option<Duration> {
+        use std::convert::TryFrom;
+
+        u32::try_from(self.batches)
+            .ok()
+            .filter(|batches| *batches > 0)
+            .map(|batches| self.indexing_duration / batches)
+    }
+}
+
 /// Health of the Meilisearch server.
 ///
 /// # Example
@@ -1136,6 +1620,7 @@ pub struct ClientStats {
 /// ```
 #[derive(Debug, Clone, Deserialize)]
 pub struct Health {
+    /// `"available"` when the server is ready to handle requests.
     pub status: String,
 }
 
@@ -1159,6 +1644,46 @@ pub struct Version {
     pub pkg_version: String,
 }
 
+impl Version {
+    /// Parse [`Version::pkg_version`] into its `(major, minor, patch)` components.
+    ///
+    /// Pre-release and build metadata suffixes (`-rc.1`, `+build`) are ignored.
+    /// Returns `None` if the version is not of the form `major.minor.patch`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let version = client.get_version().await.unwrap();
+    ///
+    /// if version.pkg_version_semver() >= Some((1, 10, 0)) {
+    ///     // federated search is available
+    /// }
+    /// # });
+    /// ```
+    pub fn pkg_version_semver(&self) -> Option<(u32, u32, u32)> {
+        let version = self
+            .pkg_version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default();
+        let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => {
+                Some((major, minor, patch))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use big_s::S;
@@ -1173,6 +1698,103 @@ mod tests {
         id: String,
     }
 
+    fn succeeded_batch(uid: u32, indexed: usize, duration: &str, started_at: &str) -> Value {
+        json!({
+            "uid": uid,
+            "progress": null,
+            "details": { "receivedDocuments": indexed, "indexedDocuments": indexed },
+            "stats": {
+                "totalNbTasks": 1,
+                "status": { "succeeded": 1 },
+                "types": { "documentAdditionOrUpdate": 1 },
+                "indexUids": { "movies": 1 }
+            },
+            "duration": duration,
+            "startedAt": started_at,
+            "finishedAt": started_at
+        })
+    }
+
+    #[test]
+    fn test_throughput_report_from_batches() {
+        let batches: Vec<Batch> = serde_json::from_value(json!([
+            succeeded_batch(0, 400, "PT2S", "2022-02-03T15:17:02Z"),
+            succeeded_batch(1, 400, "PT2S", "2022-02-03T15:17:05Z"),
+        ]))
+        .unwrap();
+
+        let report = ThroughputReport::from_batches(&batches);
+        assert_eq!(report.batches, 2);
+        assert_eq!(report.indexed_documents, 800);
+        assert_eq!(report.indexing_duration, Duration::from_secs(4));
+        assert_eq!(report.documents_per_second(), 200.0);
+        assert_eq!(
+            report.average_batch_duration(),
+            Some(Duration::from_secs(2))
+        );
+
+        let report = ThroughputReport::from_batches(&[]);
+        assert_eq!(report.documents_per_second(), 0.0);
+        assert_eq!(report.average_batch_duration(), None);
+    }
+
+    #[meilisearch_test]
+    async fn test_indexing_throughput_stops_at_since() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let body = json!({
+            "results": [
+                succeeded_batch(2, 300, "PT1S", "2022-02-03T15:17:05Z"),
+                succeeded_batch(1, 200, "PT1S", "2022-02-03T15:17:02Z"),
+            ],
+            "total": 3,
+            "limit": 1000,
+            "from": 2,
+            "next": 0
+        });
+        let mock_res = s
+            .mock(
+                "GET",
+                "/batches?statuses=succeeded&types=documentAdditionOrUpdate&limit=1000",
+            )
+            .with_status(200)
+            .with_body(body.to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let since = OffsetDateTime::parse(
+            "2022-02-03T15:17:03Z",
+            &time::format_description::well_known::Rfc3339,
+        )
+        .unwrap();
+        let report = client.indexing_throughput(since).await?;
+
+        // the older batch and the next page are ignored
+        mock_res.assert_async().await;
+        assert_eq!(report.batches, 1);
+        assert_eq!(report.indexed_documents, 300);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_indexing_throughput(client: Client, index: Index) -> Result<(), Error> {
+        let since = OffsetDateTime::now_utc() - time::Duration::SECOND;
+        let documents: Vec<Document> = (0..50).map(|id| Document { id: id.to_string() }).collect();
+        index
+            .add_documents(&documents, Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let report = client.indexing_throughput(since).await?;
+        // other tests may index documents concurrently
+        assert!(report.indexed_documents >= documents.len());
+        assert!(report.batches >= 1);
+        assert!(report.average_batch_duration().is_some());
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_swapping_two_indexes(client: Client) {
         let index_1 = client.index("test_swapping_two_indexes_1");
@@ -1221,6 +1843,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_swap_indexes_serialization() {
+        let swaps: Vec<SwapIndexes> = vec![("a", "b").into(), SwapIndexes::new("c", S("d"))];
+
+        assert_eq!(
+            serde_json::to_value(&swaps).unwrap(),
+            serde_json::json!([{ "indexes": ["a", "b"] }, { "indexes": ["c", "d"] }])
+        );
+    }
+
     #[meilisearch_test]
     async fn test_methods_has_qualified_version_as_header() {
         let mut s = mockito::Server::new_async().await;
@@ -1323,6 +1955,27 @@ mod tests {
         assert!(keys.results.len() >= 2);
     }
 
+    #[meilisearch_test]
+    async fn test_get_keys_with_pagination(client: Client) {
+        let first = KeysQuery::new()
+            .with_limit(1)
+            .execute(&client)
+            .await
+            .unwrap();
+        let second = KeysQuery::new()
+            .with_offset(1)
+            .with_limit(1)
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert!(first.total >= 2);
+        assert_eq!((first.offset, first.limit), (0, 1));
+        assert_eq!((second.offset, second.limit), (1, 1));
+        assert_eq!(first.results.len(), 1);
+        assert_eq!(second.results.len(), 1);
+    }
+
     #[meilisearch_test]
     async fn test_delete_key(client: Client, name: String) {
         let mut key = KeyBuilder::new();
@@ -1339,6 +1992,37 @@ mod tests {
         assert!(keys.results.iter().all(|k| k.key != key.key));
     }
 
+    #[meilisearch_test]
+    async fn test_delete_keys_where(client: Client, name: String) -> Result<(), Error> {
+        for suffix in ["first", "second"].iter() {
+            let mut key = KeyBuilder::new();
+            key.with_name(format!("{name}_{suffix}"));
+            client.create_key(key).await?;
+        }
+        let mut kept = KeyBuilder::new();
+        kept.with_name(format!("kept_{name}"));
+        let kept = client.create_key(kept).await?;
+
+        let deleted = client
+            .delete_keys_where(|key| {
+                key.name
+                    .as_deref()
+                    .is_some_and(|key_name| key_name.starts_with(&name))
+            })
+            .await?;
+        assert_eq!(deleted, 2);
+
+        let keys = KeysQuery::new().with_limit(10000).execute(&client).await?;
+        assert!(keys.results.iter().all(|key| key
+            .name
+            .as_deref()
+            .is_none_or(|key_name| !key_name.starts_with(&name))));
+        assert!(keys.results.iter().any(|key| key.uid == kept.uid));
+
+        client.delete_key(&kept).await?;
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_error_delete_key(client: Client, name: String) {
         // ==> accessing a key that does not exist
@@ -1405,6 +2089,246 @@ mod tests {
         client.delete_key(key).await.unwrap();
     }
 
+    #[test]
+    fn test_pkg_version_semver() {
+        let version = |pkg_version: &str| Version {
+            commit_sha: S("b46889b5f0f2f8b91438a08a358ba8f05fc09fc1"),
+            commit_date: S("2019-11-15T09:51:54.278247+00:00"),
+            pkg_version: pkg_version.to_string(),
+        };
+
+        assert_eq!(version("1.12.3").pkg_version_semver(), Some((1, 12, 3)));
+        assert_eq!(
+            version("1.13.0-rc.1").pkg_version_semver(),
+            Some((1, 13, 0))
+        );
+        assert_eq!(version("1.2.0+build").pkg_version_semver(), Some((1, 2, 0)));
+        assert_eq!(version("1.2").pkg_version_semver(), None);
+        assert_eq!(version("1.2.3.4").pkg_version_semver(), None);
+        assert_eq!(version("v1.2.3").pkg_version_semver(), None);
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_task_with_backoff_gives_up() {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), Some("masterKey")).unwrap();
+        let mock = s
+            .mock("GET", "/tasks/1")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "uid": 1,
+                    "indexUid": "movies",
+                    "status": "enqueued",
+                    "type": "indexCreation",
+                    "details": { "primaryKey": null },
+                    "enqueuedAt": "2024-01-01T00:00:00Z"
+                })
+                .to_string(),
+            )
+            .expect(3)
+            .create_async()
+            .await;
+
+        let backoff = Backoff {
+            initial_interval: Duration::from_millis(1),
+            max_retries: Some(2),
+            ..Backoff::default()
+        };
+        let task_info: TaskInfo = serde_json::from_value(json!({
+            "taskUid": 1,
+            "indexUid": "movies",
+            "status": "enqueued",
+            "type": "indexCreation",
+            "enqueuedAt": "2024-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        let error = client
+            .wait_for_task_with_backoff(task_info, &backoff)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::TaskWaitTimeout { attempts: 3, .. }));
+        mock.assert_async().await;
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_task_with_backoff_rejects_non_finite_multiplier() {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), Some("masterKey")).unwrap();
+        let mock = s.mock("GET", "/tasks/1").expect(0).create_async().await;
+
+        let backoff = Backoff {
+            multiplier: f64::INFINITY,
+            ..Backoff::default()
+        };
+        let task_info: TaskInfo = serde_json::from_value(json!({
+            "taskUid": 1,
+            "indexUid": "movies",
+            "status": "enqueued",
+            "type": "indexCreation",
+            "enqueuedAt": "2024-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        let error = client
+            .wait_for_task_with_backoff(task_info, &backoff)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::InvalidBackoffMultiplier(multiplier) if multiplier.is_infinite()
+        ));
+        mock.assert_async().await;
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_task_with_backoff(client: Client, index: Index) {
+        let task = index
+            .add_documents(&[Document { id: S("0") }], Some("id"))
+            .await
+            .unwrap();
+
+        let status = client
+            .wait_for_task_with_backoff(task, &Backoff::default())
+            .await
+            .unwrap();
+        assert!(status.is_success());
+    }
+
+    #[meilisearch_test]
+    async fn test_get_version(client: Client) {
+        let version = client.get_version().await.unwrap();
+        assert!(version.pkg_version_semver().is_some());
+    }
+
+    #[meilisearch_test]
+    async fn test_health(client: Client) {
+        let health = client.health().await.unwrap();
+        assert_eq!(health.status, "available");
+        assert!(client.is_healthy().await);
+    }
+
+    #[meilisearch_test]
+    async fn test_is_healthy_unreachable_server() {
+        let client = Client::new("http://localhost:1", Some("masterKey")).unwrap();
+        assert!(client.health().await.is_err());
+        assert!(!client.is_healthy().await);
+    }
+
+    #[meilisearch_test]
+    async fn test_get_stats(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(&[Document { id: S("0") }], Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let stats = client.get_stats().await?;
+        assert!(stats.database_size > 0);
+        assert!(stats.last_update.is_some());
+        assert_eq!(stats.indexes[index.uid.as_str()].number_of_documents, 1);
+        assert_eq!(
+            stats.indexes[index.uid.as_str()].number_of_documents,
+            index.get_stats().await?.number_of_documents
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_index_stats(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(&[Document { id: S("0") }], Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let stats = client.get_index_stats(&index.uid).await?;
+        assert_eq!(stats.number_of_documents, 1);
+        assert!(!stats.is_indexing);
+        assert_eq!(stats.field_distribution.get("id"), Some(&1));
+
+        let error = client
+            .get_index_stats("test_get_index_stats_missing")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_request_timeout(client: Client) {
+        let http_client = crate::reqwest::ReqwestClient::with_timeout(
+            client.api_key.as_deref(),
+            std::time::Duration::from_nanos(1),
+        )
+        .unwrap();
+        let client = Client::new_with_client(&client.host, client.api_key, http_client);
+
+        let error = client.get_stats().await.unwrap_err();
+        assert!(matches!(error, Error::RequestTimeout));
+    }
+
+    #[test]
+    fn test_action_serialization() {
+        let actions = [
+            (Action::All, "*"),
+            (Action::Search, "search"),
+            (Action::DocumentsAll, "documents.*"),
+            (Action::DocumentsAdd, "documents.add"),
+            (Action::IndexesAll, "indexes.*"),
+            (Action::IndexesSwap, "indexes.swap"),
+            (Action::TasksAll, "tasks.*"),
+            (Action::TasksCancel, "tasks.cancel"),
+            (Action::TasksDelete, "tasks.delete"),
+            (Action::SettingsAll, "settings.*"),
+            (Action::StatsAll, "stats.*"),
+            (Action::DumpsAll, "dumps.*"),
+            (Action::SnapshotsAll, "snapshots.*"),
+            (Action::SnapshotsCreate, "snapshots.create"),
+            (Action::KeyUpdate, "keys.update"),
+        ];
+
+        for &(action, name) in actions.iter() {
+            assert_eq!(serde_json::to_value(action).unwrap(), name);
+            assert_eq!(
+                serde_json::from_value::<Action>(serde_json::json!(name)).unwrap(),
+                action
+            );
+        }
+        assert!(serde_json::from_value::<Action>(serde_json::json!("documets.add")).is_err());
+    }
+
+    #[meilisearch_test]
+    async fn test_create_key_with_uid(client: Client, name: String) {
+        let uid = uuid::Uuid::new_v4().to_string();
+        let mut key = KeyBuilder::new();
+        key.with_name(&name).with_uid(&uid);
+        let created = client.create_key(key.clone()).await.unwrap();
+
+        assert_eq!(created.uid, uid);
+        assert_eq!(client.get_key(&uid).await.unwrap().key, created.key);
+
+        // creating the same key again is rejected instead of creating a duplicate
+        let error = client.create_key(key).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::ApiKeyAlreadyExists,
+                error_type: ErrorType::InvalidRequest,
+                ..
+            })
+        ));
+
+        client.delete_key(created).await.unwrap();
+    }
+
     #[meilisearch_test]
     async fn test_error_create_key(client: Client, name: String) {
         // ==> Invalid index name
@@ -1469,6 +2393,31 @@ mod tests {
         client.delete_key(key).await.unwrap();
     }
 
+    #[meilisearch_test]
+    async fn test_update_key_name_keeps_description(client: Client, description: String) {
+        let mut key = KeyBuilder::new();
+        key.with_name("test_update_key_name_keeps_description");
+        key.with_description(&description);
+        let key = client.create_key(key).await.unwrap();
+
+        let mut key_update = KeyUpdater::new(&key.uid);
+        key_update.with_name("new name");
+        assert_eq!(
+            serde_json::to_value(&key_update).unwrap(),
+            serde_json::json!({ "name": "new name" })
+        );
+
+        let key = client.update_key(&key_update).await.unwrap();
+        assert_eq!(key.name, Some(S("new name")));
+        assert_eq!(key.description, Some(description));
+
+        let fetched = client.get_key(&key.uid).await.unwrap();
+        assert_eq!(fetched.key, key.key);
+        assert_eq!(fetched.name, key.name);
+
+        client.delete_key(key).await.unwrap();
+    }
+
     #[meilisearch_test]
     async fn test_get_index(client: Client, index_uid: String) -> Result<(), Error> {
         let task = client.create_index(&index_uid, None).await?;
@@ -1487,6 +2436,45 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_wait_for_task_uid(client: Client, index: Index) -> Result<(), Error> {
+        let task_uid = index.delete_all_documents().await?.task_uid;
+
+        let task = client.wait_for_task_uid(task_uid, None, None).await?;
+        assert_eq!(task.get_uid(), task_uid);
+        assert!(task.is_success());
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_index_exists(client: Client, index: Index) -> Result<(), Error> {
+        assert!(client.index_exists(&index.uid).await?);
+        assert!(
+            !client
+                .index_exists(format!("{}_missing", index.uid))
+                .await?
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_try_get_index(client: Client, index_uid: String) -> Result<(), Error> {
+        assert!(client.try_get_index(&index_uid).await?.is_none());
+
+        let task = client.create_index(&index_uid, None).await?;
+        client.wait_for_task(task, None, None).await?;
+        let index = client.try_get_index(&index_uid).await?.unwrap();
+        assert_eq!(index.uid, index_uid);
+        assert!(index.created_at.is_some());
+
+        index
+            .delete()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_error_create_index(client: Client, index: Index) -> Result<(), Error> {
         let error = client
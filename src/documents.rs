@@ -13,6 +13,16 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 /// - `filterable`
 /// - `sortable`
 ///
+/// ## Struct attribute
+/// Use the `#[index_config(..)]` struct attribute to configure the settings that are not tied to a field:
+/// - `max_total_hits = 1000`, the maximum number of results a search can return
+/// - `max_values_per_facet = 100`, the maximum number of values returned for each facet
+/// - `ranking_rules("words", "typo", "release_date:desc")`, custom sort rules can only use `sortable` fields
+/// - `stop_words("the", "a")`
+///
+/// The settings reference the fields by their serialized name, so the `rename` and `rename_all`
+/// serde attributes are taken into account.
+///
 /// ## Index name
 /// The name of the index will be the name of the struct converted to snake case.
 ///
@@ -25,6 +35,8 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 /// use meilisearch_sdk::client::Client;
 ///
 /// #[derive(Serialize, Deserialize, IndexConfig)]
+/// #[index_config(max_total_hits = 5000, max_values_per_facet = 200)]
+/// #[index_config(ranking_rules("words", "typo", "release_date:desc"), stop_words("the", "a"))]
 /// struct Movie {
 ///     #[index_config(primary_key)]
 ///     movie_id: u64,
@@ -39,12 +51,23 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 /// }
 ///
 /// async fn usage(client: Client) {
-///     // Default settings with the distinct, searchable, displayed, filterable, and sortable fields set correctly.
+///     // Default settings with the distinct, searchable, displayed, filterable, and sortable fields set correctly,
+///     // along with the pagination, faceting, ranking rules and stop words settings.
 ///     let settings: Settings = Movie::generate_settings();
 ///     // Index created with the name `movie` and the primary key set to `movie_id`
 ///     let index: Index = Movie::generate_index(&client).await.unwrap();
 /// }
 /// ```
+///
+/// A custom ranking rule on a field that is not `sortable` doesn't compile:
+/// ```compile_fail
+/// # use meilisearch_sdk::documents::IndexConfig;
+/// #[derive(IndexConfig)]
+/// #[index_config(ranking_rules("words", "price:asc"))]
+/// struct Product {
+///     price: u64,
+/// }
+/// ```
 pub use meilisearch_index_setting_macro::IndexConfig;
 
 use crate::client::Client;
@@ -66,11 +89,16 @@ pub trait IndexConfig {
     async fn generate_index<Http: HttpClient>(client: &Client<Http>) -> Result<Index<Http>, Task>;
 }
 
+/// A page of documents, returned by [`Index::get_documents`] and [`DocumentsQuery::execute`].
 #[derive(Debug, Clone, Deserialize)]
 pub struct DocumentsResults<T> {
+    /// The documents of the page.
     pub results: Vec<T>,
+    /// Maximum number of documents in the page.
     pub limit: u32,
+    /// Number of documents skipped before the page.
     pub offset: u32,
+    /// Total number of documents matching the query, use it to know when to stop paging.
     pub total: u32,
 }
 
@@ -82,14 +110,21 @@ pub struct DocumentQuery<'a, Http: HttpClient> {
     /// The fields that should appear in the documents. By default, all of the fields are present.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<&'a str>>,
+
+    /// Return the embeddings of the document in its `_vectors` field.
+    ///
+    /// **Default: `false`**
+    #[serde(rename = "retrieveVectors", skip_serializing_if = "Option::is_none")]
+    pub retrieve_vectors: Option<bool>,
 }
 
 impl<'a, Http: HttpClient> DocumentQuery<'a, Http> {
     #[must_use]
-    pub fn new(index: &Index<Http>) -> DocumentQuery<Http> {
+    pub fn new(index: &Index<Http>) -> DocumentQuery<'_, Http> {
         DocumentQuery {
             index,
             fields: None,
+            retrieve_vectors: None,
         }
     }
 
@@ -117,6 +152,39 @@ impl<'a, Http: HttpClient> DocumentQuery<'a, Http> {
         self
     }
 
+    /// Ask Meilisearch to return the embeddings of the document in its `_vectors` field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, documents::*};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct Movie {
+    ///     id: usize,
+    ///     #[serde(rename = "_vectors")]
+    ///     vectors: Option<serde_json::Value>,
+    /// }
+    ///
+    /// let index = client.index("document_query_with_retrieve_vectors");
+    /// let mut document_query = DocumentQuery::new(&index);
+    ///
+    /// document_query.with_retrieve_vectors(true);
+    /// // document_query.execute::<Movie>("1") now fills `vectors`
+    /// ```
+    pub fn with_retrieve_vectors<'b>(
+        &'b mut self,
+        retrieve_vectors: bool,
+    ) -> &'b mut DocumentQuery<'a, Http> {
+        self.retrieve_vectors = Some(retrieve_vectors);
+        self
+    }
+
     /// Execute the get document query.
     ///
     /// # Example
@@ -196,17 +264,31 @@ pub struct DocumentsQuery<'a, Http: HttpClient> {
     /// Read the [dedicated guide](https://www.meilisearch.com/docs/learn/fine_tuning_results/filtering#filter-basics) to learn the syntax.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<&'a str>,
+
+    /// The primary keys of the documents to retrieve.
+    ///
+    /// When a filter is also set, only the documents matching both are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+
+    /// Return the embeddings of the documents in their `_vectors` field.
+    ///
+    /// **Default: `false`**
+    #[serde(rename = "retrieveVectors", skip_serializing_if = "Option::is_none")]
+    pub retrieve_vectors: Option<bool>,
 }
 
 impl<'a, Http: HttpClient> DocumentsQuery<'a, Http> {
     #[must_use]
-    pub fn new(index: &Index<Http>) -> DocumentsQuery<Http> {
+    pub fn new(index: &Index<Http>) -> DocumentsQuery<'_, Http> {
         DocumentsQuery {
             index,
             offset: None,
             limit: None,
             fields: None,
             filter: None,
+            ids: None,
+            retrieve_vectors: None,
         }
     }
 
@@ -282,6 +364,64 @@ impl<'a, Http: HttpClient> DocumentsQuery<'a, Http> {
         self
     }
 
+    /// Only retrieve the documents with these primary keys, in a single request.
+    ///
+    /// When a [filter](DocumentsQuery::with_filter) is also set, only the documents matching both are returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, documents::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = client.index("my_index");
+    ///
+    /// let mut documents_query = DocumentsQuery::new(&index);
+    ///
+    /// documents_query.with_ids(&["1", "42"]);
+    /// ```
+    pub fn with_ids<'b>(&'b mut self, ids: &[&'a str]) -> &'b mut DocumentsQuery<'a, Http> {
+        self.ids = Some(ids.to_vec());
+        self
+    }
+
+    /// Include the stored embeddings of the documents in their `_vectors` field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, documents::*};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct Movie {
+    ///     id: usize,
+    ///     #[serde(rename = "_vectors")]
+    ///     vectors: Option<serde_json::Value>,
+    /// }
+    ///
+    /// let index = client.index("my_index");
+    ///
+    /// let mut documents_query = DocumentsQuery::new(&index);
+    ///
+    /// documents_query.with_retrieve_vectors(true);
+    /// // documents_query.execute::<Movie>() now fills `vectors`
+    /// ```
+    pub fn with_retrieve_vectors<'b>(
+        &'b mut self,
+        retrieve_vectors: bool,
+    ) -> &'b mut DocumentsQuery<'a, Http> {
+        self.retrieve_vectors = Some(retrieve_vectors);
+        self
+    }
+
     /// Execute the get documents query.
     ///
     /// # Example
@@ -332,7 +472,7 @@ pub struct DocumentDeletionQuery<'a, Http: HttpClient> {
 
 impl<'a, Http: HttpClient> DocumentDeletionQuery<'a, Http> {
     #[must_use]
-    pub fn new(index: &Index<Http>) -> DocumentDeletionQuery<Http> {
+    pub fn new(index: &Index<Http>) -> DocumentDeletionQuery<'_, Http> {
         DocumentDeletionQuery {
             index,
             filter: None,
@@ -355,9 +495,15 @@ impl<'a, Http: HttpClient> DocumentDeletionQuery<'a, Http> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{client::Client, errors::*, indexes::*};
+    use crate::{
+        client::Client,
+        errors::*,
+        indexes::*,
+        settings::{Embedder, FacetingSettings, PaginationSetting},
+    };
     use meilisearch_test_macro::meilisearch_test;
     use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
 
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
     struct MyObject {
@@ -388,6 +534,21 @@ mod tests {
         video_id: u64,
     }
 
+    #[allow(unused)]
+    #[derive(Serialize, IndexConfig)]
+    #[serde(rename_all = "camelCase")]
+    struct RenamedClips {
+        #[index_config(primary_key)]
+        #[serde(rename = "id")]
+        clip_id: u64,
+        #[index_config(distinct)]
+        owner_name: String,
+        #[index_config(displayed, searchable)]
+        title: String,
+        #[index_config(filterable, sortable, displayed)]
+        release_date: String,
+    }
+
     async fn setup_test_index(client: &Client, index: &Index) -> Result<(), Error> {
         let t0 = index
             .add_documents(
@@ -436,6 +597,188 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_get_documents_with_ids(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let documents = DocumentsQuery::new(&index)
+            .with_ids(&["1", "3", "42"])
+            .execute::<MyObject>()
+            .await?;
+        let mut ids: Vec<usize> = documents.results.iter().filter_map(|doc| doc.id).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 3]);
+
+        index
+            .set_filterable_attributes(["kind"])
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        let documents = DocumentsQuery::new(&index)
+            .with_ids(&["1", "3"])
+            .with_filter("kind = title")
+            .execute::<MyObject>()
+            .await?;
+        assert_eq!(
+            documents.results,
+            vec![MyObject {
+                id: Some(3),
+                kind: "title".into()
+            }]
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_documents_total_while_paging(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        let documents: Vec<MyObject> = (0..25)
+            .map(|id| MyObject {
+                id: Some(id),
+                kind: "text".into(),
+            })
+            .collect();
+        index
+            .add_documents(&documents, None)
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        index
+            .set_filterable_attributes(["kind"])
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        for filter in [None, Some("kind = text")].iter() {
+            let mut ids = Vec::new();
+            let mut offset = 0;
+            loop {
+                let mut query = DocumentsQuery::new(&index);
+                query.with_offset(offset).with_limit(10);
+                if let Some(filter) = *filter {
+                    query.with_filter(filter);
+                }
+                let page = query.execute::<MyObject>().await?;
+
+                assert_eq!(page.total, 25);
+                assert_eq!(page.limit, 10);
+                assert_eq!(page.offset as usize, offset);
+                ids.extend(page.results.iter().filter_map(|document| document.id));
+
+                offset += 10;
+                if offset >= page.total as usize {
+                    break;
+                }
+            }
+            ids.sort_unstable();
+            assert_eq!(ids, (0..25).collect::<Vec<_>>());
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct MyObjectWithVectors {
+        id: usize,
+        #[serde(rename = "_vectors")]
+        vectors: Option<serde_json::Value>,
+    }
+
+    async fn setup_vectors_index(client: &Client, index: &Index) -> Result<(), Error> {
+        let mut embedders = HashMap::new();
+        embedders.insert("default".to_string(), Embedder::user_provided(2));
+        index
+            .set_embedders(&embedders)
+            .await?
+            .wait_for_completion(client, None, None)
+            .await?;
+
+        let task = index
+            .add_documents(
+                &[
+                    serde_json::json!({ "id": 0, "_vectors": { "default": [0.1, 0.2] } }),
+                    serde_json::json!({ "id": 1, "_vectors": { "default": [0.3, 0.4] } }),
+                ],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(client, None, None)
+            .await?;
+        assert!(task.is_success());
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_documents_with_retrieve_vectors(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        setup_vectors_index(&client, &index).await?;
+
+        let mut query = DocumentsQuery::new(&index);
+        query.with_retrieve_vectors(true);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["retrieveVectors"],
+            serde_json::json!(true)
+        );
+
+        let documents = query.execute::<MyObjectWithVectors>().await?;
+        let mut ids: Vec<usize> = documents
+            .results
+            .iter()
+            .map(|document| document.id)
+            .collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![0, 1]);
+        for document in &documents.results {
+            let vectors = document.vectors.as_ref().unwrap();
+            assert!(vectors["default"]["embeddings"].is_array());
+        }
+
+        let documents = DocumentsQuery::new(&index)
+            .execute::<MyObjectWithVectors>()
+            .await?;
+        assert_eq!(documents.results.len(), 2);
+        assert!(documents
+            .results
+            .iter()
+            .all(|document| document.vectors.is_none()));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_document_with_retrieve_vectors(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        setup_vectors_index(&client, &index).await?;
+
+        let mut query = DocumentQuery::new(&index);
+        query.with_retrieve_vectors(true);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["retrieveVectors"],
+            serde_json::json!(true)
+        );
+
+        let document = query.execute::<MyObjectWithVectors>("1").await?;
+        assert_eq!(document.id, 1);
+        assert_eq!(
+            document.vectors.unwrap()["default"]["embeddings"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let document = DocumentQuery::new(&index)
+            .execute::<MyObjectWithVectors>("1")
+            .await?;
+        assert_eq!(document.id, 1);
+        assert!(document.vectors.is_none());
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_delete_documents_with(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -628,6 +971,74 @@ Hint: It might not be working because you're not up to date with the Meilisearch
         Ok(())
     }
 
+    #[allow(unused)]
+    #[derive(IndexConfig)]
+    #[index_config(max_total_hits = 5000, max_values_per_facet = 200)]
+    struct PaginatedClips {
+        #[index_config(filterable)]
+        genres: Vec<String>,
+    }
+
+    #[test]
+    fn test_settings_generated_by_macro_with_pagination_and_faceting() {
+        let settings: Settings = PaginatedClips::generate_settings();
+        let video_settings: Settings = VideoClips::generate_settings();
+
+        assert_eq!(
+            settings.pagination,
+            Some(PaginationSetting {
+                max_total_hits: 5000
+            })
+        );
+        assert_eq!(
+            settings.faceting,
+            Some(FacetingSettings {
+                max_values_per_facet: 200
+            })
+        );
+        assert_eq!(video_settings.pagination, None);
+        assert_eq!(video_settings.faceting, None);
+    }
+
+    #[allow(unused)]
+    #[derive(IndexConfig)]
+    #[index_config(ranking_rules("words", "typo", "release_date:desc"))]
+    #[index_config(stop_words("the", "a"))]
+    struct RankedClips {
+        #[index_config(sortable)]
+        release_date: String,
+    }
+
+    #[test]
+    fn test_settings_generated_by_macro_with_ranking_rules_and_stop_words() {
+        let settings: Settings = RankedClips::generate_settings();
+        let video_settings: Settings = VideoClips::generate_settings();
+
+        assert_eq!(
+            settings.ranking_rules.unwrap(),
+            ["words", "typo", "release_date:desc"]
+        );
+        assert_eq!(settings.stop_words.unwrap(), ["the", "a"]);
+        assert_eq!(video_settings.ranking_rules, None);
+        assert_eq!(video_settings.stop_words, None);
+    }
+
+    #[test]
+    fn test_settings_generated_by_macro_use_serde_names() {
+        let settings: Settings = RenamedClips::generate_settings();
+
+        assert_eq!(
+            settings.displayed_attributes.unwrap(),
+            ["title", "releaseDate"]
+        );
+        assert_eq!(settings.filterable_attributes.unwrap(), ["releaseDate"]);
+        assert_eq!(settings.sortable_attributes.unwrap(), ["releaseDate"]);
+        assert_eq!(
+            settings.distinct_attribute,
+            Some(Some("ownerName".to_string()))
+        );
+    }
+
     #[meilisearch_test]
     async fn test_settings_generated_by_macro(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -673,6 +1084,7 @@ Hint: It might not be working because you're not up to date with the Meilisearch
 
         Ok(())
     }
+    #[allow(dead_code)]
     #[derive(Serialize, Deserialize, IndexConfig)]
     struct Movie {
         #[index_config(primary_key)]
@@ -38,7 +38,28 @@
 //! # });
 //! ```
 
-use crate::{client::Client, errors::Error, request::*, task_info::TaskInfo};
+use std::time::Duration;
+
+use time::OffsetDateTime;
+
+use crate::{
+    client::Client,
+    errors::Error,
+    request::*,
+    task_info::TaskInfo,
+    tasks::{DumpCreation, FailedTask, SucceededTask, Task, TaskType},
+};
+
+/// A dump written to the dumps directory, returned by [`Client::wait_for_dump`].
+#[derive(Debug, Clone)]
+pub struct DumpInfo {
+    /// The uid of the dump, which is also the name of the `.dump` file.
+    pub uid: String,
+    /// The uid of the task that created the dump.
+    pub task_uid: u32,
+    /// When the dump was written.
+    pub finished_at: OffsetDateTime,
+}
 
 /// Dump related methods.
 /// See the [dumps](crate::dumps) module.
@@ -84,6 +105,73 @@ impl<Http: HttpClient> Client<Http> {
             )
             .await
     }
+
+    /// Wait until the dump created by a [`Client::create_dump`] task is written, and get its uid.
+    ///
+    /// `interval` and `timeout` work the same as in [`Client::wait_for_task`], but dumps of large databases can take a long time so consider a larger `timeout`.
+    ///
+    /// Returns the [`MeilisearchError`](crate::errors::MeilisearchError) of the task if the dump creation failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// # use std::time::Duration;
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// #
+    /// let task_info = client.create_dump().await.unwrap();
+    /// let dump = client
+    ///     .wait_for_dump(task_info, None, Some(Duration::from_secs(60)))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// println!("{}.dump is ready", dump.uid);
+    /// # });
+    /// ```
+    pub async fn wait_for_dump(
+        &self,
+        task_id: impl AsRef<u32>,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<DumpInfo, Error> {
+        match self.wait_for_task(task_id, interval, timeout).await? {
+            Task::Succeeded {
+                content:
+                    SucceededTask {
+                        uid,
+                        finished_at,
+                        update_type:
+                            TaskType::DumpCreation {
+                                details:
+                                    Some(DumpCreation {
+                                        dump_uid: Some(dump_uid),
+                                    }),
+                            },
+                        ..
+                    },
+            } => Ok(DumpInfo {
+                uid: dump_uid,
+                task_uid: uid,
+                finished_at,
+            }),
+            Task::Failed {
+                content: FailedTask { error, .. },
+            } => Err(Error::Meilisearch(error)),
+            task => Err(Error::Other(
+                format!(
+                    "The task {} did not create a dump: {:?}",
+                    task.get_uid(),
+                    task
+                )
+                .into(),
+            )),
+        }
+    }
 }
 
 /// Alias for [`create_dump`](Client::create_dump).
@@ -94,9 +182,8 @@ pub async fn create_dump<Http: HttpClient>(client: &Client<Http>) -> Result<Task
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{client::*, tasks::*};
+    use crate::{client::*, indexes::Index, tasks::*};
     use meilisearch_test_macro::meilisearch_test;
-    use std::time::Duration;
 
     #[meilisearch_test]
     async fn test_dumps_success_creation(client: Client) -> Result<(), Error> {
@@ -127,4 +214,31 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[meilisearch_test]
+    async fn test_wait_for_dump(client: Client) -> Result<(), Error> {
+        let task_info = client.create_dump().await?;
+        let task_uid = task_info.task_uid;
+
+        let dump = client
+            .wait_for_dump(task_info, None, Some(Duration::from_secs(60)))
+            .await?;
+
+        assert_eq!(dump.task_uid, task_uid);
+        assert!(!dump.uid.is_empty());
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_dump_of_another_task(client: Client, index: Index) -> Result<(), Error> {
+        let task_info = index.delete_all_documents().await?;
+
+        let error = client
+            .wait_for_dump(task_info, None, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Other(_)));
+        Ok(())
+    }
 }
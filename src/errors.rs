@@ -14,6 +14,17 @@ pub enum Error {
 
     #[error(transparent)]
     MeilisearchCommunication(#[from] MeilisearchCommunicationError),
+    /// The server answered with an unexpected status code and a body that isn't a Meilisearch error.
+    ///
+    /// This usually comes from something standing between the SDK and Meilisearch, like a proxy returning an HTML error page.
+    #[error(
+        "Unexpected response: the server responded with a {status_code}.\nurl: {url}\nbody: {body}"
+    )]
+    UnexpectedResponse {
+        status_code: u16,
+        body: String,
+        url: String,
+    },
     /// The Meilisearch server returned an invalid JSON for a request.
     #[error("Error parsing response JSON: {}", .0)]
     ParseError(#[from] serde_json::Error),
@@ -21,6 +32,19 @@ pub enum Error {
     /// A timeout happened while waiting for an update to complete.
     #[error("A task did not succeed in time.")]
     Timeout,
+    /// An HTTP request exceeded the timeout of its [`ReqwestClient`](crate::reqwest::ReqwestClient).
+    #[error("The HTTP request did not complete in time.")]
+    RequestTimeout,
+    /// [`Client::wait_for_task_with_backoff`](crate::client::Client::wait_for_task_with_backoff) polled a task
+    /// for its whole [`Backoff`](crate::tasks::Backoff) budget without seeing it finish.
+    #[error("The task was still pending after {attempts} attempts over {elapsed:?}.")]
+    TaskWaitTimeout {
+        attempts: u32,
+        elapsed: std::time::Duration,
+    },
+    /// The `multiplier` of a [`Backoff`](crate::tasks::Backoff) is NaN or infinite.
+    #[error("Invalid backoff multiplier `{0}`: it must be a finite number.")]
+    InvalidBackoffMultiplier(f64),
     /// This Meilisearch SDK generated an invalid request (which was not sent).
     ///
     /// It probably comes from an invalid API key resulting in an invalid HTTP header.
@@ -38,6 +62,9 @@ pub enum Error {
     /// It is not possible to generate an already expired tenant token.
     #[error("The provided expires_at is already expired.")]
     TenantTokensExpiredSignature,
+    /// The tenant token is malformed or was not signed with the given api key.
+    #[error("The tenant token is malformed or was not signed with the provided api key.")]
+    TenantTokensInvalidSignature,
 
     /// When jsonwebtoken cannot generate the token successfully.
     #[cfg(not(target_arch = "wasm32"))]
@@ -49,21 +76,51 @@ pub enum Error {
     #[error("HTTP request failed: {}", .0)]
     HttpError(#[from] reqwest::Error),
 
+    /// The `fetch` API of the browser failed to send a request of the [`WasmClient`](crate::wasm::WasmClient), or to read its response.
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    #[error("HTTP request failed: {}", .0)]
+    FetchError(String),
+
     // The library formatting the query parameters encountered an error.
     #[error("Internal Error: could not parse the query parameters: {}", .0)]
     Yaup(#[from] yaup::Error),
 
     // The library validating the format of an uuid.
-    #[cfg(not(target_arch = "wasm32"))]
     #[error("The uid of the token has bit an uuid4 format: {}", .0)]
     Uuid(#[from] uuid::Error),
 
+    /// Some attributes used to sort a search are not in the `sortableAttributes` of the index.
+    ///
+    /// Only returned when the sort validation of the [`SearchQuery`](crate::search::SearchQuery) is enabled.
+    #[error("The following attributes are not sortable: {}. Add them to the `sortableAttributes` setting of the index.", .0.join(", "))]
+    UnsortableAttributes(Vec<String>),
+
+    /// Some settings were rejected before being sent to Meilisearch.
+    #[error(transparent)]
+    InvalidSettings(#[from] SettingsValidationError),
+
+    /// The delimiter of a CSV payload is not a single ASCII character, as required by Meilisearch.
+    #[error("Invalid CSV delimiter `{0}`: Meilisearch only accepts a single ASCII character.")]
+    InvalidCsvDelimiter(char),
+
     // Error thrown in case the version of the Uuid is not v4.
     #[error("The uid provided to the token is not of version uuidv4")]
     InvalidUuid4Version,
 
-    #[error(transparent)]
-    Other(Box<dyn std::error::Error + Send + Sync + 'static>),
+    /// Any other error, for example one of the transport of a custom [`HttpClient`](crate::request::HttpClient).
+    ///
+    /// The wrapped error is returned by [`std::error::Error::source`], so the whole chain can be reported.
+    #[error("{0}")]
+    Other(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+}
+
+/// The reasons the SDK rejects [`Settings`](crate::settings::Settings) before sending them to Meilisearch.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum SettingsValidationError {
+    /// A ranking rule is not a built-in rule nor a custom `attribute:asc` / `attribute:desc` rule.
+    #[error("Invalid ranking rule `{rule}`: {reason}.")]
+    InvalidRankingRule { rule: String, reason: String },
 }
 
 #[derive(Debug, Clone, Deserialize, Error)]
@@ -108,6 +165,43 @@ pub struct MeilisearchError {
     pub error_link: String,
 }
 
+impl MeilisearchError {
+    /// The fields Meilisearch could not choose between when inferring the primary key of an index.
+    ///
+    /// Returns `None` unless the error code is [`ErrorCode::IndexPrimaryKeyMultipleCandidatesFound`].
+    /// Meilisearch only names the first candidates in its message, so the list may not be exhaustive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::errors::*;
+    /// let error: MeilisearchError = serde_json::from_str(r#"{
+    ///     "message": "The primary key inference failed as the engine found 2 fields ending with `id` in their names: 'id' and 'author_id'. Please specify the primary key manually using the `primaryKey` query parameter.",
+    ///     "code": "index_primary_key_multiple_candidates_found",
+    ///     "type": "invalid_request",
+    ///     "link": "https://docs.meilisearch.com/errors#index_primary_key_multiple_candidates_found"
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(error.primary_key_candidates().unwrap(), ["id", "author_id"]);
+    /// ```
+    #[must_use]
+    pub fn primary_key_candidates(&self) -> Option<Vec<String>> {
+        if self.error_code != ErrorCode::IndexPrimaryKeyMultipleCandidatesFound {
+            return None;
+        }
+
+        // the candidates are the only single-quoted words of the message
+        let candidates = self
+            .error_message
+            .split('\'')
+            .skip(1)
+            .step_by(2)
+            .map(ToString::to_string)
+            .collect();
+        Some(candidates)
+    }
+}
+
 /// The type of error that was encountered.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -151,6 +245,8 @@ pub enum ErrorCode {
     InvalidState,
     PrimaryKeyInferenceFailed,
     IndexPrimaryKeyAlreadyPresent,
+    IndexPrimaryKeyNoCandidateFound,
+    IndexPrimaryKeyMultipleCandidatesFound,
     InvalidStoreFile,
     MaxFieldsLimitExceeded,
     MissingDocumentId,
@@ -181,6 +277,8 @@ pub enum ErrorCode {
     InvalidApiKeyIndexes,
     InvalidApiKeyExpiresAt,
     ApiKeyNotFound,
+    InvalidApiKeyUid,
+    ApiKeyAlreadyExists,
     MissingTaskFilters,
     MissingIndexUid,
     InvalidIndexOffset,
@@ -254,11 +352,13 @@ pub enum ErrorCode {
     MissingApiKeyExpiresAt,
     InvalidApiKeyLimit,
     InvalidApiKeyOffset,
+    FeatureNotEnabled,
 
-    /// That's unexpected. Please open a GitHub issue after ensuring you are
-    /// using the supported version of the Meilisearch server.
-    #[serde(other)]
-    Unknown,
+    /// An error code this version of the SDK doesn't know about, with the code as sent by Meilisearch.
+    ///
+    /// Newer Meilisearch versions regularly introduce error codes, so match on it rather than expecting it to be unreachable.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 pub const MEILISEARCH_VERSION_HINT: &str = "Hint: It might not be working because you're not up to date with the Meilisearch version that updated the get_documents_with method";
@@ -311,10 +411,64 @@ mod test {
         )
         .unwrap();
 
-        assert_eq!(error.error_code, ErrorCode::Unknown);
+        assert_eq!(
+            error.error_code,
+            ErrorCode::Unknown("An unknown error".to_string())
+        );
+        assert_eq!(error.error_code.to_string(), "An unknown error");
         assert_eq!(error.error_type, ErrorType::Unknown);
     }
 
+    #[test]
+    fn test_parse_response_keeps_unexpected_responses() {
+        let error = crate::request::parse_response::<()>(
+            502,
+            200,
+            "<html>Bad Gateway</html>",
+            "http://localhost:7700/indexes".to_string(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::UnexpectedResponse { status_code: 502, ref body, ref url }
+                if body == "<html>Bad Gateway</html>" && url == "http://localhost:7700/indexes"
+        ));
+
+        let error = crate::request::parse_response::<()>(
+            400,
+            200,
+            r#"{"message": "Index `movies` not found.", "code": "index_not_found", "type": "invalid_request", "link": "https://docs.meilisearch.com/errors#index_not_found"}"#,
+            "http://localhost:7700/indexes/movies".to_string(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_primary_key_candidates() {
+        let mut error = MeilisearchError {
+            error_message: "The primary key inference failed as the engine found 2 fields ending with `id` in their names: 'id' and 'product_id'. Please specify the primary key manually using the `primaryKey` query parameter.".to_string(),
+            error_code: ErrorCode::IndexPrimaryKeyMultipleCandidatesFound,
+            error_type: ErrorType::InvalidRequest,
+            error_link: "https://docs.meilisearch.com/errors#index_primary_key_multiple_candidates_found".to_string(),
+        };
+        assert_eq!(
+            error.primary_key_candidates(),
+            Some(vec!["id".to_string(), "product_id".to_string()])
+        );
+
+        error.error_code = ErrorCode::IndexPrimaryKeyNoCandidateFound;
+        assert_eq!(error.primary_key_candidates(), None);
+    }
+
     #[meilisearch_test]
     async fn test_error_message_parsing() {
         let error: MeilisearchError = serde_json::from_str(
@@ -352,9 +506,25 @@ mod test {
             "MeilisearchCommunicationError: The server responded with a 404.\nurl: http://localhost:7700/something"
         );
 
+        let error = Error::UnexpectedResponse {
+            status_code: 502,
+            body: "<html>Bad Gateway</html>".to_string(),
+            url: "http://localhost:7700/indexes".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Unexpected response: the server responded with a 502.\nurl: http://localhost:7700/indexes\nbody: <html>Bad Gateway</html>"
+        );
+
         let error = Error::Timeout;
         assert_eq!(error.to_string(), "A task did not succeed in time.");
 
+        let error = Error::RequestTimeout;
+        assert_eq!(
+            error.to_string(),
+            "The HTTP request did not complete in time."
+        );
+
         let error = Error::InvalidRequest;
         assert_eq!(
             error.to_string(),
@@ -418,4 +588,37 @@ mod test {
             "Internal Error: could not parse the query parameters: Test yaup error"
         );
     }
+
+    #[test]
+    fn test_error_source_chain() {
+        use std::error::Error as _;
+
+        #[derive(Debug, thiserror::Error)]
+        #[error("transport failed")]
+        struct TransportError(#[source] std::io::Error);
+
+        let io_error = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset by peer");
+        let error = Error::Other(Box::new(TransportError(io_error)));
+        assert_eq!(error.to_string(), "transport failed");
+
+        let source = error.source().unwrap();
+        assert!(source.is::<TransportError>());
+        let root = source.source().unwrap();
+        assert_eq!(root.to_string(), "reset by peer");
+        assert!(root.source().is_none());
+
+        let error = Error::ParseError(serde_json::from_str::<String>("{").unwrap_err());
+        assert!(error.source().unwrap().is::<serde_json::Error>());
+
+        let error = Error::Yaup(yaup::Error::Custom("Test yaup error".to_string()));
+        assert!(error.source().unwrap().is::<yaup::Error>());
+
+        let error = Error::Uuid(Uuid::parse_str("67e55044").unwrap_err());
+        assert!(error.source().unwrap().is::<uuid::Error>());
+
+        let error = Error::InvalidTenantToken(jsonwebtoken::errors::Error::from(InvalidToken));
+        assert!(error.source().unwrap().is::<jsonwebtoken::errors::Error>());
+
+        assert!(Error::Timeout.source().is_none());
+    }
 }
@@ -4,12 +4,27 @@ use crate::{
     request::{HttpClient, Method},
 };
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// Struct representing the experimental features result from the API.
+///
+/// Features that are missing from the response are reported as disabled.
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExperimentalFeaturesResult {
+    #[serde(default)]
     pub vector_store: bool,
+    #[serde(default)]
+    pub metrics: bool,
+    #[serde(default)]
+    pub chat_completions: bool,
+    #[serde(default)]
+    pub edit_documents_by_function: bool,
+    #[serde(default)]
+    pub composite_embedders: bool,
+    /// The features this version of the SDK doesn't know about yet.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
 }
 
 /// Struct representing the experimental features request.
@@ -33,6 +48,14 @@ pub struct ExperimentalFeatures<'a, Http: HttpClient> {
     client: &'a Client<Http>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vector_store: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_completions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edit_documents_by_function: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub composite_embedders: Option<bool>,
 }
 
 impl<'a, Http: HttpClient> ExperimentalFeatures<'a, Http> {
@@ -41,6 +64,10 @@ impl<'a, Http: HttpClient> ExperimentalFeatures<'a, Http> {
         ExperimentalFeatures {
             client,
             vector_store: None,
+            metrics: None,
+            chat_completions: None,
+            edit_documents_by_function: None,
+            composite_embedders: None,
         }
     }
 
@@ -49,6 +76,33 @@ impl<'a, Http: HttpClient> ExperimentalFeatures<'a, Http> {
         self
     }
 
+    /// Enable the `/metrics` route, exposing Prometheus metrics.
+    pub fn set_metrics(&mut self, metrics: bool) -> &mut Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Enable the [chat completions](crate::chats) routes.
+    pub fn set_chat_completions(&mut self, chat_completions: bool) -> &mut Self {
+        self.chat_completions = Some(chat_completions);
+        self
+    }
+
+    /// Enable [`Index::update_documents_by_function`](crate::indexes::Index::update_documents_by_function).
+    pub fn set_edit_documents_by_function(
+        &mut self,
+        edit_documents_by_function: bool,
+    ) -> &mut Self {
+        self.edit_documents_by_function = Some(edit_documents_by_function);
+        self
+    }
+
+    /// Allow the [composite embedders](crate::settings::Embedder::Composite).
+    pub fn set_composite_embedders(&mut self, composite_embedders: bool) -> &mut Self {
+        self.composite_embedders = Some(composite_embedders);
+        self
+    }
+
     /// Get all the experimental features
     ///
     /// # Example
@@ -90,13 +144,58 @@ impl<'a, Http: HttpClient> ExperimentalFeatures<'a, Http> {
     /// });
     /// ```
     pub async fn update(&self) -> Result<ExperimentalFeaturesResult, Error> {
-        self.client
-            .http_client
-            .request::<(), &Self, ExperimentalFeaturesResult>(
-                &format!("{}/experimental-features", self.client.host),
+        self.client.set_experimental_features(self).await
+    }
+}
+
+/// Experimental features related methods.
+impl<Http: HttpClient> Client<Http> {
+    /// Get the state of the [experimental features](https://www.meilisearch.com/docs/reference/api/experimental_features) of the instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::Client;
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    ///     let features = client.get_experimental_features().await.unwrap();
+    ///     println!("metrics enabled: {}", features.metrics);
+    /// });
+    /// ```
+    pub async fn get_experimental_features(&self) -> Result<ExperimentalFeaturesResult, Error> {
+        ExperimentalFeatures::new(self).get().await
+    }
+
+    /// Enable or disable [experimental features](https://www.meilisearch.com/docs/reference/api/experimental_features).
+    ///
+    /// Only the features set in `features` are updated, the others are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::Client, features::ExperimentalFeatures};
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    ///     let mut features = ExperimentalFeatures::new(&client);
+    ///     features.set_metrics(true);
+    ///     let features = client.set_experimental_features(&features).await.unwrap();
+    ///     assert!(features.metrics);
+    /// });
+    /// ```
+    pub async fn set_experimental_features(
+        &self,
+        features: &ExperimentalFeatures<'_, Http>,
+    ) -> Result<ExperimentalFeaturesResult, Error> {
+        self.http_client
+            .request::<(), &ExperimentalFeatures<Http>, ExperimentalFeaturesResult>(
+                &format!("{}/experimental-features", self.host),
                 Method::Patch {
                     query: (),
-                    body: self,
+                    body: features,
                 },
                 200,
             )
@@ -109,6 +208,37 @@ mod tests {
     use super::*;
     use meilisearch_test_macro::meilisearch_test;
 
+    #[test]
+    fn test_experimental_features_result_keeps_unknown_features() {
+        let features: ExperimentalFeaturesResult = serde_json::from_str(
+            r#"{ "metrics": true, "containsFilter": true, "network": false }"#,
+        )
+        .unwrap();
+
+        assert!(features.metrics);
+        assert!(!features.vector_store);
+        assert_eq!(
+            features.other.get("containsFilter"),
+            Some(&Value::Bool(true))
+        );
+        assert_eq!(features.other.get("network"), Some(&Value::Bool(false)));
+    }
+
+    #[meilisearch_test]
+    async fn test_client_experimental_features(client: Client) {
+        let mut features = ExperimentalFeatures::new(&client);
+        features.set_metrics(true);
+        let res = client.set_experimental_features(&features).await.unwrap();
+        assert!(res.metrics);
+
+        let res = client.get_experimental_features().await.unwrap();
+        assert!(res.metrics);
+
+        features.set_metrics(false);
+        let res = client.set_experimental_features(&features).await.unwrap();
+        assert!(!res.metrics);
+    }
+
     #[meilisearch_test]
     async fn test_experimental_features_get(client: Client) {
         let mut features = ExperimentalFeatures::new(&client);
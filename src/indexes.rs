@@ -8,8 +8,13 @@ use crate::{
     tasks::*,
     DefaultHttpClient,
 };
+use futures::{stream, Stream, TryStreamExt};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    time::Duration,
+};
 use time::OffsetDateTime;
 
 /// A Meilisearch [index](https://www.meilisearch.com/docs/learn/core_concepts/indexes).
@@ -113,7 +118,10 @@ impl<Http: HttpClient> Index<Http> {
         })
     }
 
-    /// Update an [Index].
+    /// Update an [Index] with its current `primary_key`.
+    ///
+    /// The `primary_key` of an index can only be changed while it contains no documents.
+    /// See [`Index::update_builder`] to choose the fields to update.
     ///
     /// # Example
     ///
@@ -160,6 +168,43 @@ impl<Http: HttpClient> Index<Http> {
         index_update.execute().await
     }
 
+    /// Create an [`IndexUpdater`] to update this [Index] field by field.
+    ///
+    /// The `primary_key` of an index can only be changed while it contains no documents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("index_update_builder", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// client
+    ///     .index("index_update_builder")
+    ///     .update_builder()
+    ///     .with_primary_key("special_id")
+    ///     .execute()
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let index = client.get_index("index_update_builder").await.unwrap();
+    ///
+    /// assert_eq!(index.primary_key, Some("special_id".to_string()));
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn update_builder(&self) -> IndexUpdater<'_, Http> {
+        IndexUpdater::new(self, &self.client)
+    }
+
     /// Delete the index.
     ///
     /// # Example
@@ -228,16 +273,90 @@ impl<Http: HttpClient> Index<Http> {
         &self,
         body: &SearchQuery<'_, Http>,
     ) -> Result<SearchResults<T>, Error> {
+        let body = body.with_consistent_pagination();
+        self.validate_sort(&body).await?;
+
         self.client
             .http_client
             .request::<(), &SearchQuery<Http>, SearchResults<T>>(
                 &format!("{}/indexes/{}/search", self.client.host, self.uid),
-                Method::Post { body, query: () },
+                Method::Post {
+                    body: &*body,
+                    query: (),
+                },
+                200,
+            )
+            .await
+    }
+
+    /// Same as [`Index::execute_query`], but with a `GET` request so CDNs and proxies can cache the results.
+    ///
+    /// The query is sent as URL parameters: lists are comma-separated and array filters are encoded in JSON.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     name: String,
+    ///     description: String,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movies = client.index("execute_get_query");
+    ///
+    /// // add some documents
+    /// # movies.add_or_replace(&[Movie{name:String::from("Interstellar"), description:String::from("Interstellar chronicles the adventures of a group of explorers who make use of a newly discovered wormhole to surpass the limitations on human space travel and conquer the vast distances involved in an interstellar voyage.")},Movie{name:String::from("Unknown"), description:String::from("Unknown")}], Some("name")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let query = SearchQuery::new(&movies)
+    ///     .with_query("Interstellar")
+    ///     .with_attributes_to_retrieve(Selectors::Some(&["name", "description"]))
+    ///     .with_limit(5)
+    ///     .build();
+    /// let results = movies.execute_get_query::<Movie>(&query).await.unwrap();
+    ///
+    /// assert!(results.hits.len() > 0);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn execute_get_query<T: 'static + DeserializeOwned + Send + Sync>(
+        &self,
+        query: &SearchQuery<'_, Http>,
+    ) -> Result<SearchResults<T>, Error> {
+        let query = query.with_consistent_pagination();
+        self.validate_sort(&query).await?;
+        let params = search_query_to_url_params(&query)?;
+
+        self.client
+            .http_client
+            .request::<&BTreeMap<String, String>, (), SearchResults<T>>(
+                &format!("{}/indexes/{}/search", self.client.host, self.uid),
+                Method::Get { query: &params },
                 200,
             )
             .await
     }
 
+    async fn validate_sort(&self, query: &SearchQuery<'_, Http>) -> Result<(), Error> {
+        if let (true, Some(sort)) = (query.validate_sort, query.sort) {
+            let unsortable = match query.sortable_attributes {
+                Some(sortable_attributes) => unsortable_attributes(sort, sortable_attributes),
+                None => unsortable_attributes(sort, &self.get_sortable_attributes().await?),
+            };
+            if !unsortable.is_empty() {
+                return Err(Error::UnsortableAttributes(unsortable));
+            }
+        }
+        Ok(())
+    }
+
     /// Search for documents matching a specific query in the index.
     ///
     /// See also [`Index::execute_query`].
@@ -275,10 +394,59 @@ impl<Http: HttpClient> Index<Http> {
     /// # });
     /// ```
     #[must_use]
-    pub fn search(&self) -> SearchQuery<Http> {
+    pub fn search(&self) -> SearchQuery<'_, Http> {
         SearchQuery::new(self)
     }
 
+    /// Search for the values of a facet matching a [`FacetSearchQuery`].
+    ///
+    /// The facet must be a [filterable attribute](Index::set_filterable_attributes) of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     name: String,
+    ///     genre: String,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movies = client.index("facet_search");
+    /// # movies.set_filterable_attributes(["genre"]).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # movies.add_documents(&[Movie { name: String::from("Interstellar"), genre: String::from("scifi") }], Some("name")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let query = FacetSearchQuery::new(&movies, "genre")
+    ///     .with_facet_query("sci")
+    ///     .build();
+    /// let res = movies.facet_search(&query).await.unwrap();
+    ///
+    /// assert_eq!(res.facet_hits[0].value, "scifi");
+    /// assert_eq!(res.facet_hits[0].count, 1);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn facet_search(
+        &self,
+        body: &FacetSearchQuery<'_, Http>,
+    ) -> Result<FacetSearchResponse, Error> {
+        self.client
+            .http_client
+            .request::<(), &FacetSearchQuery<Http>, FacetSearchResponse>(
+                &format!("{}/indexes/{}/facet-search", self.client.host, self.uid),
+                Method::Post { body, query: () },
+                200,
+            )
+            .await
+    }
+
     /// Get one document using its unique id.
     ///
     /// Serde is needed. Add `serde = {version="1.0", features=["derive"]}` in the dependencies section of your Cargo.toml.
@@ -465,7 +633,7 @@ impl<Http: HttpClient> Index<Http> {
         &self,
         documents_query: &DocumentsQuery<'_, Http>,
     ) -> Result<DocumentsResults<T>, Error> {
-        if documents_query.filter.is_some() {
+        if documents_query.filter.is_some() || documents_query.ids.is_some() {
             let url = format!("{}/indexes/{}/documents/fetch", self.client.host, self.uid);
             return self
                 .client
@@ -480,13 +648,18 @@ impl<Http: HttpClient> Index<Http> {
                 )
                 .await
                 .map_err(|err| match err {
-                    Error::MeilisearchCommunication(error) => {
-                        Error::MeilisearchCommunication(MeilisearchCommunicationError {
-                            status_code: error.status_code,
-                            url: error.url,
-                            message: Some(format!("{}.", MEILISEARCH_VERSION_HINT)),
-                        })
-                    }
+                    Error::MeilisearchCommunication(MeilisearchCommunicationError {
+                        status_code,
+                        url,
+                        ..
+                    })
+                    | Error::UnexpectedResponse {
+                        status_code, url, ..
+                    } => Error::MeilisearchCommunication(MeilisearchCommunicationError {
+                        status_code,
+                        url,
+                        message: Some(format!("{}.", MEILISEARCH_VERSION_HINT)),
+                    }),
                     Error::Meilisearch(error) => Error::Meilisearch(MeilisearchError {
                         error_code: error.error_code,
                         error_link: error.error_link,
@@ -513,6 +686,174 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Get the number of documents in the [Index], without fetching any of them.
+    ///
+    /// The count comes from the documents route, queried with a `limit` of 0, so it only includes the documents already indexed:
+    /// the documents of the tasks still enqueued or processing aren't counted.
+    /// [`Index::get_stats`] returns the same number, along with statistics that are more costly to compute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     name: String,
+    /// }
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("get_document_count");
+    /// # movie_index.add_or_replace(&[Movie{name:String::from("Interstellar")}, Movie{name:String::from("Apollo 13")}], Some("name")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let count = movie_index.get_document_count().await.unwrap();
+    ///
+    /// assert_eq!(count, 2);
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_document_count(&self) -> Result<usize, Error> {
+        let documents = DocumentsQuery::new(self)
+            .with_limit(0)
+            .execute::<serde_json::Value>()
+            .await?;
+        Ok(documents.total as usize)
+    }
+
+    /// Stream all the documents of the index, fetching them `batch_size` at a time.
+    ///
+    /// `batch_size` = Optional parameter that allows you to specify the number of documents fetched per request.
+    ///
+    /// **`batch_size` is 1000 by default**, a `batch_size` of 0 is treated as 1.
+    ///
+    /// If a request fails, the error is yielded by the stream, which then ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use futures::TryStreamExt;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: usize,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("get_all_documents_stream");
+    /// # let movies: Vec<Movie> = (0..30).map(|id| Movie { id }).collect();
+    /// # movie_index.add_documents(&movies, Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let movies: Vec<Movie> = movie_index
+    ///     .get_all_documents_stream::<Movie>(Some(10))
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(movies.len(), 30);
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub fn get_all_documents_stream<T: DeserializeOwned + 'static + Send + Sync>(
+        &self,
+        batch_size: Option<usize>,
+    ) -> impl Stream<Item = Result<T, Error>> + '_ {
+        let batch_size = batch_size.unwrap_or(1000).max(1);
+
+        stream::try_unfold((0, false), move |(offset, done)| async move {
+            if done {
+                return Ok(None);
+            }
+
+            let mut query = DocumentsQuery::new(self);
+            query.with_offset(offset).with_limit(batch_size);
+            let documents = self.get_documents_with::<T>(&query).await?;
+
+            let fetched = documents.results.len();
+            let offset = offset + fetched;
+            let done = fetched < batch_size || offset >= documents.total as usize;
+
+            let documents = documents.results.into_iter().map(Ok::<T, Error>);
+            Ok::<_, Error>(Some((stream::iter(documents), (offset, done))))
+        })
+        .try_flatten()
+    }
+
+    /// Write all the documents of the index to `writer` as ndjson, one document per line, and return the number of documents written.
+    ///
+    /// The documents are fetched `batch_size` at a time, and the next batch is only fetched once the previous one was written:
+    /// a slow writer slows down the export instead of buffering the whole index in memory.
+    ///
+    /// If fetching a batch or writing to `writer` fails, the export stops and the error is returned.
+    /// The documents written before the failure are left in `writer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: usize,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("export_ndjson");
+    /// # let movies: Vec<Movie> = (0..30).map(|id| Movie { id }).collect();
+    /// # movie_index.add_documents(&movies, Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let mut backup = futures::io::Cursor::new(Vec::new());
+    /// let exported = movie_index.export_ndjson(&mut backup, 10).await.unwrap();
+    ///
+    /// assert_eq!(exported, 30);
+    /// assert_eq!(backup.into_inner().split(|byte| *byte == b'\n').filter(|line| !line.is_empty()).count(), 30);
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn export_ndjson<W: futures_io::AsyncWrite + Unpin>(
+        &self,
+        mut writer: W,
+        batch_size: usize,
+    ) -> Result<usize, Error> {
+        use futures::AsyncWriteExt;
+
+        let documents = self.get_all_documents_stream::<serde_json::Value>(Some(batch_size));
+        futures::pin_mut!(documents);
+
+        let mut exported = 0;
+        let mut line = Vec::new();
+        while let Some(document) = documents.try_next().await? {
+            line.clear();
+            serde_json::to_writer(&mut line, &document)?;
+            line.push(b'\n');
+            writer
+                .write_all(&line)
+                .await
+                .map_err(|error| Error::Other(Box::new(error)))?;
+            exported += 1;
+        }
+
+        writer
+            .flush()
+            .await
+            .map_err(|error| Error::Other(Box::new(error)))?;
+        Ok(exported)
+    }
+
     /// Add a list of documents or replace them if they already exist.
     ///
     /// If you send an already existing document (same id) the **whole existing document** will be overwritten by the new document.
@@ -579,6 +920,7 @@ impl<Http: HttpClient> Index<Http> {
         } else {
             format!("{}/indexes/{}/documents", self.client.host, self.uid)
         };
+
         self.client
             .http_client
             .request::<(), &[T], TaskInfo>(
@@ -668,6 +1010,91 @@ impl<Http: HttpClient> Index<Http> {
         self.add_or_replace(documents, primary_key).await
     }
 
+    /// Add a raw json payload, an array of documents, and update them if they already.
+    ///
+    /// It configures the correct content type for json data, without deserializing the payload.
+    ///
+    /// If you send an already existing document (same id) the old document will be only partially updated according to the fields of the new document.
+    /// Thus, any fields not present in the new document are kept and remained unchanged.
+    ///
+    /// To completely overwrite a document, check out the [`Index::add_documents_json`] documents method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("update_documents_json");
+    ///
+    /// let task = movie_index.update_documents_json(
+    ///     r#"[{ "id": 1, "body": "doggo" }, { "id": 2, "body": "catto" }]"#.as_bytes(),
+    ///     Some("id"),
+    ///   ).await.unwrap();
+    /// // Meilisearch may take some time to execute the request so we are going to wait till it's completed
+    /// client.wait_for_task(task, None, None).await.unwrap();
+    ///
+    /// let movies = movie_index.get_documents::<serde_json::Value>().await.unwrap();
+    /// assert_eq!(movies.results.len(), 2);
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn update_documents_json<T: futures_io::AsyncRead + Send + Sync + 'static>(
+        &self,
+        payload: T,
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.add_or_update_unchecked_payload(payload, "application/json", primary_key)
+            .await
+    }
+
+    /// Add a raw json payload, an array of documents, to meilisearch.
+    ///
+    /// It configures the correct content type for json data, without deserializing the payload.
+    /// This is useful to forward documents received from another service.
+    ///
+    /// If you send an already existing document (same id) the **whole existing document** will be overwritten by the new document.
+    /// Fields previously in the document not present in the new document are removed.
+    ///
+    /// For a partial update of the document see [`Index::update_documents_json`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("add_documents_json");
+    ///
+    /// let task = movie_index.add_documents_json(
+    ///     r#"[{ "id": 1, "body": "doggo" }, { "id": 2, "body": "catto" }]"#.as_bytes(),
+    ///     Some("id"),
+    ///   ).await.unwrap();
+    /// // Meilisearch may take some time to execute the request so we are going to wait till it's completed
+    /// client.wait_for_task(task, None, None).await.unwrap();
+    ///
+    /// let movies = movie_index.get_documents::<serde_json::Value>().await.unwrap();
+    /// assert_eq!(movies.results.len(), 2);
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn add_documents_json<T: futures_io::AsyncRead + Send + Sync + 'static>(
+        &self,
+        payload: T,
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.add_or_replace_unchecked_payload(payload, "application/json", primary_key)
+            .await
+    }
+
     /// Add a raw ndjson payload and update them if they already.
     ///
     /// It configures the correct content type for ndjson data.
@@ -850,6 +1277,86 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Add a raw csv payload whose values are separated by `delimiter` instead of a comma.
+    ///
+    /// The `delimiter` must be a single ASCII character, like `;` or `\t`, otherwise [`Error::InvalidCsvDelimiter`] is returned without sending the payload.
+    ///
+    /// See [`Index::add_documents_csv`] for the other parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movie_index = client.index("add_documents_csv_with_delimiter");
+    ///
+    /// let task = movie_index.add_documents_csv_with_delimiter(
+    ///     "id;body\n1;\"doggo\"\n2;\"catto\"".as_bytes(),
+    ///     Some("id"),
+    ///     ';',
+    ///   ).await.unwrap();
+    /// client.wait_for_task(task, None, None).await.unwrap();
+    ///
+    /// let movies = movie_index.get_documents::<serde_json::Value>().await.unwrap();
+    /// assert_eq!(movies.results.len(), 2);
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn add_documents_csv_with_delimiter<
+        T: futures_io::AsyncRead + Send + Sync + 'static,
+    >(
+        &self,
+        payload: T,
+        primary_key: Option<&str>,
+        delimiter: char,
+    ) -> Result<TaskInfo, Error> {
+        let query = CsvPayloadQuery::new(primary_key, delimiter)?;
+        self.client
+            .http_client
+            .stream_request::<&CsvPayloadQuery, T, TaskInfo>(
+                &format!("{}/indexes/{}/documents", self.client.host, self.uid),
+                Method::Post {
+                    query: &query,
+                    body: payload,
+                },
+                "text/csv",
+                202,
+            )
+            .await
+    }
+
+    /// Add a raw csv payload whose values are separated by `delimiter`, and update the documents if they already exist.
+    ///
+    /// See [`Index::add_documents_csv_with_delimiter`] and [`Index::update_documents_csv`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn update_documents_csv_with_delimiter<
+        T: futures_io::AsyncRead + Send + Sync + 'static,
+    >(
+        &self,
+        payload: T,
+        primary_key: Option<&str>,
+        delimiter: char,
+    ) -> Result<TaskInfo, Error> {
+        let query = CsvPayloadQuery::new(primary_key, delimiter)?;
+        self.client
+            .http_client
+            .stream_request::<&CsvPayloadQuery, T, TaskInfo>(
+                &format!("{}/indexes/{}/documents", self.client.host, self.uid),
+                Method::Put {
+                    query: &query,
+                    body: payload,
+                },
+                "text/csv",
+                202,
+            )
+            .await
+    }
+
     /// Add a list of documents and update them if they already.
     ///
     /// If you send an already existing document (same id) the old document will be only partially updated according to the fields of the new document.
@@ -1203,6 +1710,130 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Delete the documents matching a filter.
+    ///
+    /// This is a shorthand for [`Index::delete_documents_with`].
+    /// The attributes used in the filter must be [filterable](Index::set_filterable_attributes), otherwise the returned task fails with [`ErrorCode::InvalidDocumentFilter`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #    id: usize,
+    /// #    genre: String,
+    /// # }
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = client.index("delete_documents_by_filter");
+    /// # index.set_filterable_attributes(["genre"]).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # index.add_or_replace(&[Movie { id: 1, genre: String::from("horror") }, Movie { id: 2, genre: String::from("comedy") }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// index.delete_documents_by_filter("genre = horror")
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn delete_documents_by_filter(
+        &self,
+        filter: impl AsRef<str>,
+    ) -> Result<TaskInfo, Error> {
+        let mut query = DocumentDeletionQuery::new(self);
+        query.with_filter(filter.as_ref());
+        self.delete_documents_with(&query).await
+    }
+
+    /// Edit the documents with a [RHAI](https://rhai.rs/book/) function run by Meilisearch.
+    ///
+    /// The function can read and modify each document through the `doc` variable, and read `context` through the `context` variable.
+    /// Only the documents matching `filter` are edited, or all of them if it is `None`.
+    ///
+    /// **This is an experimental feature**, it must be enabled with [`ExperimentalFeatures::set_edit_documents_by_function`](crate::features::ExperimentalFeatures::set_edit_documents_by_function).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, features::ExperimentalFeatures};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #    id: usize,
+    /// #    title: String,
+    /// # }
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # let mut features = ExperimentalFeatures::new(&client);
+    /// # let enabled = features.get().await.unwrap().edit_documents_by_function;
+    /// # features.set_edit_documents_by_function(true).update().await.unwrap();
+    /// let index = client.index("update_documents_by_function");
+    /// # index.add_or_replace(&[Movie { id: 1, title: String::from("Carol") }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// index
+    ///     .update_documents_by_function(
+    ///         "doc.title = `${doc.title}${context.suffix}`",
+    ///         None,
+    ///         Some(serde_json::json!({ "suffix": " (1952)" })),
+    ///     )
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let movie: Movie = index.get_document("1").await.unwrap();
+    /// assert_eq!(movie.title, "Carol (1952)");
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # features.set_edit_documents_by_function(enabled).update().await.unwrap();
+    /// # });
+    /// ```
+    pub async fn update_documents_by_function(
+        &self,
+        function: &str,
+        filter: Option<&str>,
+        context: Option<serde_json::Value>,
+    ) -> Result<TaskInfo, Error> {
+        #[derive(Serialize)]
+        struct DocumentsEdition<'a> {
+            function: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            filter: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            context: Option<serde_json::Value>,
+        }
+
+        self.client
+            .http_client
+            .request::<(), DocumentsEdition, TaskInfo>(
+                &format!("{}/indexes/{}/documents/edit", self.client.host, self.uid),
+                Method::Post {
+                    query: (),
+                    body: DocumentsEdition {
+                        function,
+                        filter,
+                        context,
+                    },
+                },
+                202,
+            )
+            .await
+    }
+
     /// Alias for the [`Index::update`] method.
     pub async fn set_primary_key(
         &mut self,
@@ -1311,6 +1942,7 @@ impl<Http: HttpClient> Index<Http> {
     ///     Task::Processing { content } => content.uid,
     ///     Task::Failed { content } => content.task.uid,
     ///     Task::Succeeded { content } => content.uid,
+    ///     Task::Canceled { content } => content.uid,
     /// };
     ///
     /// assert_eq!(task.get_task_uid(), from_index);
@@ -1477,6 +2109,9 @@ impl<Http: HttpClient> Index<Http> {
     ///
     /// **`batch_size` is 1000 by default**
     ///
+    /// The batches are sent one after the other and one [`TaskInfo`] is returned per batch.
+    /// The documents are only indexed once all these tasks succeeded, see [`Client::wait_for_task`].
+    ///
     /// # Example
     ///
     /// ```
@@ -1529,7 +2164,7 @@ impl<Http: HttpClient> Index<Http> {
         primary_key: Option<&str>,
     ) -> Result<Vec<TaskInfo>, Error> {
         let mut task = Vec::with_capacity(documents.len());
-        for document_batch in documents.chunks(batch_size.unwrap_or(1000)) {
+        for document_batch in documents.chunks(batch_size.unwrap_or(1000).max(1)) {
             task.push(self.add_documents(document_batch, primary_key).await?);
         }
         Ok(task)
@@ -1543,6 +2178,9 @@ impl<Http: HttpClient> Index<Http> {
     ///
     /// **`batch_size` is 1000 by default**
     ///
+    /// The batches are sent one after the other and one [`TaskInfo`] is returned per batch.
+    /// The documents are only indexed once all these tasks succeeded, see [`Client::wait_for_task`].
+    ///
     /// # Example
     ///
     /// ```
@@ -1617,7 +2255,7 @@ impl<Http: HttpClient> Index<Http> {
         primary_key: Option<&str>,
     ) -> Result<Vec<TaskInfo>, Error> {
         let mut task = Vec::with_capacity(documents.len());
-        for document_batch in documents.chunks(batch_size.unwrap_or(1000)) {
+        for document_batch in documents.chunks(batch_size.unwrap_or(1000).max(1)) {
             task.push(self.add_or_update(document_batch, primary_key).await?);
         }
         Ok(task)
@@ -1676,7 +2314,7 @@ pub struct IndexUpdater<'a, Http: HttpClient> {
 }
 
 impl<'a, Http: HttpClient> IndexUpdater<'a, Http> {
-    pub fn new(uid: impl AsRef<str>, client: &Client<Http>) -> IndexUpdater<Http> {
+    pub fn new(uid: impl AsRef<str>, client: &Client<Http>) -> IndexUpdater<'_, Http> {
         IndexUpdater {
             client,
             primary_key: None,
@@ -1685,6 +2323,8 @@ impl<'a, Http: HttpClient> IndexUpdater<'a, Http> {
     }
     /// Define the new `primary_key` to set on the [Index].
     ///
+    /// The task fails with [`ErrorCode::IndexPrimaryKeyAlreadyPresent`] if the index already contains documents.
+    ///
     /// # Example
     ///
     /// ```
@@ -1795,9 +2435,20 @@ impl<'a, Http: HttpClient> AsRef<IndexUpdater<'a, Http>> for IndexUpdater<'a, Ht
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IndexStats {
+    /// Number of documents in the index.
     pub number_of_documents: usize,
+    /// `true` while the index is processing a task.
     pub is_indexing: bool,
+    /// Number of documents containing each field.
     pub field_distribution: HashMap<String, usize>,
+    /// Number of documents for which every configured embedder has produced an embedding.
+    ///
+    /// Only returned by Meilisearch versions that support embedders.
+    pub number_of_embedded_documents: Option<usize>,
+    /// Total number of embeddings stored in the index.
+    ///
+    /// Only returned by Meilisearch versions that support embedders.
+    pub number_of_embeddings: Option<usize>,
 }
 
 /// An [`IndexesQuery`] containing filter and pagination parameters when searching for [Indexes](Index).
@@ -1858,7 +2509,7 @@ pub struct IndexesQuery<'a, Http: HttpClient> {
 
 impl<'a, Http: HttpClient> IndexesQuery<'a, Http> {
     #[must_use]
-    pub fn new(client: &Client<Http>) -> IndexesQuery<Http> {
+    pub fn new(client: &Client<Http>) -> IndexesQuery<'_, Http> {
         IndexesQuery {
             client,
             offset: None,
@@ -1970,6 +2621,29 @@ impl<'a, Http: HttpClient> IndexesQuery<'a, Http> {
     }
 }
 
+/// Query parameters of the csv payloads sent with a custom delimiter.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvPayloadQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_key: Option<&'a str>,
+    csv_delimiter: String,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<'a> CsvPayloadQuery<'a> {
+    fn new(primary_key: Option<&'a str>, delimiter: char) -> Result<CsvPayloadQuery<'a>, Error> {
+        if !delimiter.is_ascii() {
+            return Err(Error::InvalidCsvDelimiter(delimiter));
+        }
+        Ok(CsvPayloadQuery {
+            primary_key,
+            csv_delimiter: delimiter.to_string(),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IndexesResults<Http: HttpClient = DefaultHttpClient> {
     pub results: Vec<Index<Http>>,
@@ -1982,9 +2656,11 @@ pub struct IndexesResults<Http: HttpClient = DefaultHttpClient> {
 mod tests {
     use super::*;
 
+    use crate::errors::ErrorCode;
     use big_s::S;
+    use futures::StreamExt;
     use meilisearch_test_macro::meilisearch_test;
-    use serde_json::json;
+    use serde_json::{json, Value};
 
     #[meilisearch_test]
     async fn test_from_value(client: Client) {
@@ -2018,6 +2694,311 @@ mod tests {
         assert_eq!(res.client.api_key, idx.client.api_key);
     }
 
+    #[test]
+    fn test_index_stats_embeddings() {
+        let stats: IndexStats = serde_json::from_value(json!({
+            "numberOfDocuments": 10,
+            "isIndexing": true,
+            "fieldDistribution": { "id": 10 },
+            "numberOfEmbeddedDocuments": 4,
+            "numberOfEmbeddings": 8,
+        }))
+        .unwrap();
+        assert_eq!(stats.number_of_embedded_documents, Some(4));
+        assert_eq!(stats.number_of_embeddings, Some(8));
+
+        let stats: IndexStats = serde_json::from_value(json!({
+            "numberOfDocuments": 10,
+            "isIndexing": false,
+            "fieldDistribution": {},
+        }))
+        .unwrap();
+        assert_eq!(stats.number_of_embedded_documents, None);
+        assert_eq!(stats.number_of_embeddings, None);
+    }
+
+    #[meilisearch_test]
+    async fn test_get_stats(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(&[json!({ "id": 0 })], Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let stats = index.get_stats().await?;
+        assert_eq!(stats.number_of_documents, 1);
+        if let (Some(embedded), Some(embeddings)) = (
+            stats.number_of_embedded_documents,
+            stats.number_of_embeddings,
+        ) {
+            // No embedder is configured on the index.
+            assert_eq!(embedded, 0);
+            assert_eq!(embeddings, 0);
+        }
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_all_documents_stream(client: Client, index: Index) -> Result<(), Error> {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Object {
+            id: usize,
+        }
+
+        let objects: Vec<Object> = (0..250).map(|id| Object { id }).collect();
+        index
+            .add_documents(&objects, Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let mut ids: Vec<usize> = index
+            .get_all_documents_stream::<Object>(Some(100))
+            .map_ok(|object| object.id)
+            .try_collect()
+            .await?;
+        ids.sort_unstable();
+        assert_eq!(ids, (0..250).collect::<Vec<_>>());
+
+        let ids: Vec<usize> = index
+            .get_all_documents_stream::<Object>(Some(50))
+            .map_ok(|object| object.id)
+            .try_collect()
+            .await?;
+        assert_eq!(ids.len(), 250);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_all_documents_stream_with_zero_batch_size(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        let documents: Vec<Value> = (0..5).map(|id| json!({ "id": id })).collect();
+        index
+            .add_documents(&documents, Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let documents: Vec<Value> = index
+            .get_all_documents_stream::<Value>(Some(0))
+            .try_collect()
+            .await?;
+        assert_eq!(documents.len(), 5);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_export_ndjson(client: Client, index: Index) -> Result<(), Error> {
+        let documents: Vec<Value> = (0..25)
+            .map(|id| json!({ "id": id, "title": format!("movie {id}") }))
+            .collect();
+        index
+            .add_documents(&documents, Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let mut output = futures::io::Cursor::new(Vec::new());
+        let exported = index.export_ndjson(&mut output, 10).await?;
+        assert_eq!(exported, 25);
+
+        let output = String::from_utf8(output.into_inner()).unwrap();
+        let mut exported: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        exported.sort_by_key(|document| document["id"].as_u64());
+        assert_eq!(exported, documents);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_export_ndjson_error(client: Client, index_uid: String) {
+        let index = client.index(index_uid);
+        let mut output = futures::io::Cursor::new(Vec::new());
+
+        let error = index.export_ndjson(&mut output, 10).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            })
+        ));
+        assert!(output.into_inner().is_empty());
+    }
+
+    #[meilisearch_test]
+    async fn test_get_all_documents_stream_error(client: Client, index_uid: String) {
+        let index = client.index(index_uid);
+        let results: Vec<Result<Value, Error>> = index
+            .get_all_documents_stream::<Value>(None)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            }))
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_documents_in_batches(client: Client, index: Index) -> Result<(), Error> {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Object {
+            id: usize,
+            value: String,
+        }
+
+        let objects: Vec<Object> = (0..25)
+            .map(|id| Object {
+                id,
+                value: S("original"),
+            })
+            .collect();
+        let tasks = index
+            .add_documents_in_batches(&objects, Some(10), Some("id"))
+            .await?;
+        assert_eq!(tasks.len(), 3);
+        for task in tasks {
+            assert!(task
+                .wait_for_completion(&client, None, None)
+                .await?
+                .is_success());
+        }
+        assert_eq!(index.get_stats().await?.number_of_documents, 25);
+
+        let updates: Vec<Value> = (0..5)
+            .map(|id| json!({ "id": id, "value": "updated" }))
+            .collect();
+        let tasks = index
+            .update_documents_in_batches(&updates, Some(2), None)
+            .await?;
+        assert_eq!(tasks.len(), 3);
+        client
+            .wait_for_task(tasks.last().unwrap(), None, None)
+            .await?;
+
+        let object: Object = index.get_document("4").await?;
+        assert_eq!(object.value, "updated");
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_delete_documents_by_filter(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(
+                &[
+                    json!({ "id": 0, "kind": "keep" }),
+                    json!({ "id": 1, "kind": "drop" }),
+                    json!({ "id": 2, "kind": "drop" }),
+                ],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        // `kind` is not filterable yet
+        let task = index
+            .delete_documents_by_filter("kind = drop")
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(matches!(
+            task.unwrap_failure(),
+            MeilisearchError {
+                error_code: ErrorCode::InvalidDocumentFilter,
+                ..
+            }
+        ));
+
+        index
+            .set_filterable_attributes(["kind"])
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        let task = index
+            .delete_documents_by_filter(String::from("kind = drop"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+        assert_eq!(index.get_stats().await?.number_of_documents, 1);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_update_documents_by_function(client: Client, index: Index) -> Result<(), Error> {
+        // The experimental features are shared by every test running against the server
+        let mut features = crate::features::ExperimentalFeatures::new(&client);
+        let enabled = features.get().await?.edit_documents_by_function;
+
+        let result = async {
+            features.set_edit_documents_by_function(true);
+            features.update().await?;
+
+            index
+                .add_documents(
+                    &[
+                        json!({ "id": 0, "title": "first" }),
+                        json!({ "id": 1, "title": "second" }),
+                    ],
+                    Some("id"),
+                )
+                .await?
+                .wait_for_completion(&client, None, None)
+                .await?;
+            index
+                .set_filterable_attributes(["id"])
+                .await?
+                .wait_for_completion(&client, None, None)
+                .await?;
+
+            let task = index
+                .update_documents_by_function(
+                    "doc.title = `${doc.title} ${context.suffix}`",
+                    Some("id = 1"),
+                    Some(json!({ "suffix": "edited" })),
+                )
+                .await?
+                .wait_for_completion(&client, None, None)
+                .await?;
+
+            assert!(matches!(
+                task,
+                Task::Succeeded {
+                    content: SucceededTask {
+                        update_type: TaskType::DocumentEdition {
+                            details: Some(DocumentEdition {
+                                edited_documents: Some(1),
+                                ..
+                            })
+                        },
+                        ..
+                    }
+                }
+            ));
+
+            let first: Value = index.get_document("0").await?;
+            let second: Value = index.get_document("1").await?;
+            assert_eq!(first["title"], json!("first"));
+            assert_eq!(second["title"], json!("second edited"));
+            Ok(())
+        }
+        .await;
+
+        features.set_edit_documents_by_function(enabled);
+        features.update().await?;
+        result
+    }
+
     #[meilisearch_test]
     async fn test_fetch_info(mut index: Index) {
         let res = index.fetch_info().await;
@@ -2027,6 +3008,58 @@ mod tests {
         assert!(index.primary_key.is_none());
     }
 
+    #[meilisearch_test]
+    async fn test_update_builder(client: Client, index: Index) -> Result<(), Error> {
+        let task = index
+            .update_builder()
+            .with_primary_key("id")
+            .execute()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+        assert_eq!(
+            client.get_index(&index.uid).await?.primary_key.as_deref(),
+            Some("id")
+        );
+
+        index
+            .add_documents(&[json!({ "id": 1, "other_id": 2 })], None)
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        let task = index
+            .update_builder()
+            .with_primary_key("other_id")
+            .execute()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert_eq!(
+            task.unwrap_failure().error_code,
+            ErrorCode::IndexPrimaryKeyAlreadyPresent
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_document_count(client: Client, index: Index) -> Result<(), Error> {
+        assert_eq!(index.get_document_count().await?, 0);
+
+        index
+            .add_documents(
+                &[json!({ "id": 1 }), json!({ "id": 2 }), json!({ "id": 3 })],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        assert_eq!(index.get_document_count().await?, 3);
+        assert_eq!(index.get_stats().await?.number_of_documents, 3);
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_get_documents(index: Index) {
         #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -2105,6 +3138,36 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_add_documents_json(client: Client, index: Index) -> Result<(), Error> {
+        let json = r#"[{ "id": 1, "body": "doggo" }, { "id": 2, "body": "catto" }]"#.as_bytes();
+
+        let task = index
+            .add_documents_json(json, Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+
+        let updated_json = r#"[{ "id": 1, "second_body": "second_doggo" }]"#.as_bytes();
+        let task = index
+            .update_documents_json(updated_json, None)
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+
+        let elements = index.get_documents::<serde_json::Value>().await?;
+        assert_eq!(
+            elements.results,
+            vec![
+                json!({ "id": 1, "body": "doggo", "second_body": "second_doggo" }),
+                json!({ "id": 2, "body": "catto" }),
+            ]
+        );
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_add_documents_ndjson(client: Client, index: Index) -> Result<(), Error> {
         let ndjson = r#"{ "id": 1, "body": "doggo" }{ "id": 2, "body": "catto" }"#.as_bytes();
@@ -2159,6 +3222,41 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_add_documents_csv_with_delimiter(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        let csv_input = "id;body\n1;\"doggo, the good boy\"\n2;\"catto\"".as_bytes();
+        let task = index
+            .add_documents_csv_with_delimiter(csv_input, Some("id"), ';')
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+
+        let csv_input = "id\tsecond_body\n1\tsecond_doggo".as_bytes();
+        let task = index
+            .update_documents_csv_with_delimiter(csv_input, None, '\t')
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+
+        let document = index.get_document::<serde_json::Value>("1").await?;
+        assert_eq!(
+            document,
+            json!({ "id": "1", "body": "doggo, the good boy", "second_body": "second_doggo" })
+        );
+
+        let error = index
+            .add_documents_csv_with_delimiter("id".as_bytes(), None, '§')
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidCsvDelimiter('§')));
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_add_documents_csv(client: Client, index: Index) -> Result<(), Error> {
         let csv_input = "id,body\n1,\"doggo\"\n2,\"catto\"".as_bytes();
@@ -2212,7 +3310,39 @@ mod tests {
         Ok(())
     }
     #[meilisearch_test]
+    async fn test_concurrent_add_documents_to_new_index(
+        client: Client,
+        index_uid: String,
+    ) -> Result<(), Error> {
+        let index = client.index(&index_uid);
+
+        let tasks = futures::future::join_all((0..5).map(|i| {
+            let index = index.clone();
+            async move {
+                index
+                    .add_documents(&[json!({ "id": i, "body": "doggo" })], Some("id"))
+                    .await
+            }
+        }))
+        .await;
+
+        for task in tasks {
+            let task = task?.wait_for_completion(&client, None, None).await?;
+            assert!(task.is_success());
+        }
 
+        let documents = index.get_documents::<serde_json::Value>().await?;
+        assert_eq!(documents.results.len(), 5);
+
+        index
+            .delete()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        Ok(())
+    }
+
+    #[meilisearch_test]
     async fn test_get_one_task(client: Client, index: Index) -> Result<(), Error> {
         let task = index
             .delete_all_documents()
@@ -162,10 +162,15 @@ impl AsRef<Key> for Key {
     }
 }
 
+/// Patch of an existing [Key], sent by [`Client::update_key`].
+///
+/// Meilisearch only allows updating the `name` and `description` of a key. Fields left to `None` are not sent, so they keep their current value.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct KeyUpdater {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing)]
     pub key: String,
@@ -289,20 +294,20 @@ impl AsRef<KeyUpdater> for KeyUpdater {
 #[derive(Debug, Serialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct KeysQuery {
-    /// The number of documents to skip.
+    /// The number of keys to skip.
     ///
-    /// If the value of the parameter `offset` is `n`, the `n` first documents (ordered by relevance) will not be returned.
+    /// If the value of the parameter `offset` is `n`, the `n` first keys will not be returned.
     /// This is helpful for pagination.
     ///
-    /// Example: If you want to skip the first document, set offset to `1`.
+    /// Example: If you want to skip the first key, set offset to `1`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<usize>,
-    /// The maximum number of documents returned.
+    /// The maximum number of keys returned.
     ///
-    /// If the value of the parameter `limit` is `n`, there will never be more than `n` documents in the response.
+    /// If the value of the parameter `limit` is `n`, there will never be more than `n` keys in the response.
     /// This is helpful for pagination.
     ///
-    /// Example: If you don't want to get more than two documents, set limit to `2`.
+    /// Example: If you don't want to get more than two keys, set limit to `2`.
     ///
     /// **Default: `20`**
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -595,6 +600,8 @@ impl KeyBuilder {
 
     /// Add a uid to the [Key].
     ///
+    /// The uid must be a valid UUID v4. Since Meilisearch rejects the creation of a key with an existing uid, this allows provisioning keys deterministically.
+    ///
     /// # Example
     ///
     /// ```
@@ -659,6 +666,9 @@ pub enum Action {
     /// Provides access to both [`POST`](https://www.meilisearch.com/docs/reference/api/search.md#search-in-an-index-with-post-route) and [`GET`](https://www.meilisearch.com/docs/reference/api/search.md#search-in-an-index-with-get-route) search endpoints on authorized indexes.
     #[serde(rename = "search")]
     Search,
+    /// Provides access to all the `documents.*` actions.
+    #[serde(rename = "documents.*")]
+    DocumentsAll,
     /// Provides access to the [add documents](https://www.meilisearch.com/docs/reference/api/documents.md#add-or-replace-documents) and [update documents](https://www.meilisearch.com/docs/reference/api/documents.md#add-or-update-documents) endpoints on authorized indexes.
     #[serde(rename = "documents.add")]
     DocumentsAdd,
@@ -671,6 +681,9 @@ pub enum Action {
     /// Provides access to the [create index](https://www.meilisearch.com/docs/reference/api/indexes.md#create-an-index) endpoint.
     #[serde(rename = "indexes.create")]
     IndexesCreate,
+    /// Provides access to all the `indexes.*` actions.
+    #[serde(rename = "indexes.*")]
+    IndexesAll,
     /// Provides access to the [get one index](https://www.meilisearch.com/docs/reference/api/indexes.md#get-one-index) and [list all indexes](https://www.meilisearch.com/docs/reference/api/indexes.md#list-all-indexes) endpoints. **Non-authorized `indexes` will be omitted from the response**.
     #[serde(rename = "indexes.get")]
     IndexesGet,
@@ -680,9 +693,24 @@ pub enum Action {
     /// Provides access to the [delete index](https://www.meilisearch.com/docs/reference/api/indexes.md#delete-an-index) endpoint.
     #[serde(rename = "indexes.delete")]
     IndexesDelete,
+    /// Provides access to the [swap indexes](https://www.meilisearch.com/docs/reference/api/indexes#swap-indexes) endpoint on authorized indexes.
+    #[serde(rename = "indexes.swap")]
+    IndexesSwap,
+    /// Provides access to all the `tasks.*` actions.
+    #[serde(rename = "tasks.*")]
+    TasksAll,
     /// Provides access to the [get one task](https://www.meilisearch.com/docs/reference/api/tasks.md#get-task) and [get all tasks](https://www.meilisearch.com/docs/reference/api/tasks.md#get-all-tasks) endpoints. **Tasks from non-authorized `indexes` will be omitted from the response**. Also provides access to the [get one task by index](https://www.meilisearch.com/docs/reference/api/tasks.md#get-task-by-index) and [get all tasks by index](https://www.meilisearch.com/docs/reference/api/tasks.md#get-all-tasks-by-index) endpoints on authorized indexes.
     #[serde(rename = "tasks.get")]
     TasksGet,
+    /// Provides access to the [cancel tasks](https://www.meilisearch.com/docs/reference/api/tasks#cancel-tasks) endpoint on authorized indexes.
+    #[serde(rename = "tasks.cancel")]
+    TasksCancel,
+    /// Provides access to the [delete tasks](https://www.meilisearch.com/docs/reference/api/tasks#delete-tasks) endpoint on authorized indexes.
+    #[serde(rename = "tasks.delete")]
+    TasksDelete,
+    /// Provides access to all the `settings.*` actions.
+    #[serde(rename = "settings.*")]
+    SettingsAll,
     /// Provides access to the [get settings](https://www.meilisearch.com/docs/reference/api/settings.md#get-settings) endpoint and equivalents for all subroutes on authorized indexes.
     #[serde(rename = "settings.get")]
     SettingsGet,
@@ -692,12 +720,24 @@ pub enum Action {
     /// Provides access to the [get stats of an index](https://www.meilisearch.com/docs/reference/api/stats.md#get-stats-of-an-index) endpoint and the [get stats of all indexes](https://www.meilisearch.com/docs/reference/api/stats.md#get-stats-of-all-indexes) endpoint. For the latter, **non-authorized `indexes` are omitted from the response**.
     #[serde(rename = "stats.get")]
     StatsGet,
+    /// Provides access to all the `stats.*` actions.
+    #[serde(rename = "stats.*")]
+    StatsAll,
+    /// Provides access to all the `dumps.*` actions.
+    #[serde(rename = "dumps.*")]
+    DumpsAll,
     /// Provides access to the [create dump](https://www.meilisearch.com/docs/reference/api/dump.md#create-a-dump) endpoint. **Not restricted by `indexes`.**
     #[serde(rename = "dumps.create")]
     DumpsCreate,
     /// Provides access to the [get dump status](https://www.meilisearch.com/docs/reference/api/dump.md#get-dump-status) endpoint. **Not restricted by `indexes`.**
     #[serde(rename = "dumps.get")]
     DumpsGet,
+    /// Provides access to all the `snapshots.*` actions.
+    #[serde(rename = "snapshots.*")]
+    SnapshotsAll,
+    /// Provides access to the [create snapshot](https://www.meilisearch.com/docs/reference/api/snapshots#create-a-snapshot) endpoint. **Not restricted by `indexes`.**
+    #[serde(rename = "snapshots.create")]
+    SnapshotsCreate,
     /// Provides access to the [get Meilisearch version](https://www.meilisearch.com/docs/reference/api/version.md#get-version-of-meilisearch) endpoint.
     #[serde(rename = "version")]
     Version,
@@ -720,4 +760,6 @@ pub struct KeysResults {
     pub results: Vec<Key>,
     pub limit: u32,
     pub offset: u32,
+    /// Total number of keys of the instance, across all pages.
+    pub total: u32,
 }
@@ -226,10 +226,17 @@
 //!
 //! ### Wasm support <!-- omit in TOC -->
 //!
-//! The SDK supports wasm through reqwest. You'll need to enable the `futures-unsend` feature while importing it, though.
+//! Enable the `wasm` feature and disable the default features to use the `WasmClient`, which sends the requests
+//! with the `fetch` API of the browser. `Client::new` then works out of the box in wasm, without writing an `HttpClient`.
+//!
+//! The SDK also supports wasm through reqwest. You'll need to enable the `futures-unsend` feature while importing it, though.
 #![warn(clippy::all)]
 #![allow(clippy::needless_doctest_main)]
 
+/// Module containing the [batches] of tasks.
+pub mod batches;
+/// Module containing the experimental [chats] methods.
+pub mod chats;
 /// Module containing the [`Client`] struct.
 pub mod client;
 /// Module representing the [documents] structures.
@@ -249,25 +256,50 @@ pub mod request;
 pub mod search;
 /// Module containing [`Settings`].
 pub mod settings;
+/// Module containing the [similar] documents queries.
+pub mod similar;
 /// Module containing the [snapshots] trait.
 pub mod snapshots;
 /// Module representing the [`TaskInfo`]s.
 pub mod task_info;
 /// Module representing the [`Task`]s.
 pub mod tasks;
-/// Module that generates tenant tokens.
-#[cfg(not(target_arch = "wasm32"))]
-mod tenant_tokens;
+/// Module containing the [`SearchRules`](tenant_tokens::SearchRules) and [claims](tenant_tokens::TenantTokenClaims) of tenant tokens.
+pub mod tenant_tokens;
 /// Module containing utilizes functions.
 mod utils;
 
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 
-#[cfg(feature = "reqwest")]
+/// Module containing the [`WasmClient`](wasm::WasmClient), an [`HttpClient`](request::HttpClient) using the `fetch` API of the browser.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub mod wasm;
+
+#[cfg(all(feature = "rustls-tls", feature = "native-tls"))]
+compile_error!("The `rustls-tls` and `native-tls` features are mutually exclusive, disable the default features to use `native-tls`.");
+
+#[cfg(all(
+    feature = "reqwest",
+    not(any(feature = "rustls-tls", feature = "native-tls")),
+    not(target_arch = "wasm32")
+))]
+compile_error!("The `reqwest` feature needs a TLS backend, enable either the `rustls-tls` or the `native-tls` feature.");
+
+/// Module containing the [`BlockingClient`](blocking::BlockingClient), a blocking wrapper around the [`Client`].
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub type DefaultHttpClient = wasm::WasmClient;
+
+#[cfg(all(
+    feature = "reqwest",
+    not(all(feature = "wasm", target_arch = "wasm32"))
+))]
 pub type DefaultHttpClient = reqwest::ReqwestClient;
 
-#[cfg(not(feature = "reqwest"))]
+#[cfg(not(any(feature = "reqwest", all(feature = "wasm", target_arch = "wasm32"))))]
 pub type DefaultHttpClient = std::convert::Infallible;
 
 #[cfg(test)]
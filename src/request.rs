@@ -5,7 +5,7 @@ use log::{error, trace, warn};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{from_str, to_vec};
 
-use crate::errors::{Error, MeilisearchCommunicationError, MeilisearchError};
+use crate::errors::{Error, MeilisearchError};
 
 #[derive(Debug)]
 pub enum Method<Q, B> {
@@ -65,6 +65,46 @@ impl<Q, B> Method<Q, B> {
     }
 }
 
+/// The headers of a response, as returned by [`HttpClient::request_with_headers`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseHeaders {
+    headers: Vec<(String, String)>,
+}
+
+impl ResponseHeaders {
+    #[must_use]
+    pub fn new(headers: Vec<(String, String)>) -> ResponseHeaders {
+        ResponseHeaders { headers }
+    }
+
+    /// The first value of the header `name`, compared case-insensitively.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_all(name).next()
+    }
+
+    /// Every value of the header `name`, compared case-insensitively.
+    pub fn get_all<'a>(&'a self, name: &str) -> impl Iterator<Item = &'a str> + 'a {
+        let name = name.to_string();
+        self.headers
+            .iter()
+            .filter(move |(header, _)| header.eq_ignore_ascii_case(&name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Every header of the response, in the order they were received.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+}
+
 #[cfg_attr(feature = "futures-unsend", async_trait(?Send))]
 #[cfg_attr(not(feature = "futures-unsend"), async_trait)]
 pub trait HttpClient: Clone + Send + Sync {
@@ -90,6 +130,25 @@ pub trait HttpClient: Clone + Send + Sync {
         .await
     }
 
+    /// Same as [`HttpClient::request`], but also returns the headers of the response.
+    ///
+    /// Useful to read the headers added by a proxy in front of Meilisearch, like `X-RateLimit-*`.
+    /// The default implementation returns no headers, the [`ReqwestClient`](crate::reqwest::ReqwestClient) returns all of them.
+    async fn request_with_headers<Query, Body, Output>(
+        &self,
+        url: &str,
+        method: Method<Query, Body>,
+        expected_status_code: u16,
+    ) -> Result<(Output, ResponseHeaders), Error>
+    where
+        Query: Serialize + Send + Sync,
+        Body: Serialize + Send + Sync,
+        Output: DeserializeOwned + 'static + Send,
+    {
+        let output = self.request(url, method, expected_status_code).await?;
+        Ok((output, ResponseHeaders::default()))
+    }
+
     async fn stream_request<
         Query: Serialize + Send + Sync,
         Body: futures_io::AsyncRead + Send + Sync + 'static,
@@ -129,18 +188,11 @@ pub fn parse_response<Output: DeserializeOwned>(
 
     match from_str::<MeilisearchError>(body) {
         Ok(e) => Err(Error::from(e)),
-        Err(e) => {
-            if status_code >= 400 {
-                return Err(Error::MeilisearchCommunication(
-                    MeilisearchCommunicationError {
-                        status_code,
-                        message: None,
-                        url,
-                    },
-                ));
-            }
-            Err(Error::ParseError(e))
-        }
+        Err(_) => Err(Error::UnexpectedResponse {
+            status_code,
+            body: body.to_string(),
+            url,
+        }),
     }
 }
 
@@ -175,3 +227,27 @@ impl HttpClient for Infallible {
         unreachable!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_headers() {
+        let headers = ResponseHeaders::new(vec![
+            ("content-type".to_string(), "application/json".to_string()),
+            ("Set-Cookie".to_string(), "a=1".to_string()),
+            ("set-cookie".to_string(), "b=2".to_string()),
+        ]);
+
+        assert_eq!(headers.get("Content-Type"), Some("application/json"));
+        assert_eq!(headers.get("SET-COOKIE"), Some("a=1"));
+        assert_eq!(
+            headers.get_all("set-cookie").collect::<Vec<_>>(),
+            ["a=1", "b=2"]
+        );
+        assert_eq!(headers.get("x-missing"), None);
+        assert!(!headers.is_empty());
+        assert!(ResponseHeaders::default().is_empty());
+    }
+}
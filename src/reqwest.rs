@@ -1,52 +1,209 @@
 use std::{
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use async_trait::async_trait;
 use bytes::{Bytes, BytesMut};
 use futures::{AsyncRead, Stream};
+use log::warn;
 use pin_project_lite::pin_project;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     errors::Error,
-    request::{parse_response, HttpClient, Method},
+    request::{parse_response, HttpClient, Method, ResponseHeaders},
+    utils::async_sleep,
 };
 
+/// How a [`ReqwestClient`] retries the requests Meilisearch rejects because it is overloaded.
+///
+/// Only the requests answered with a `503 Service Unavailable` or a `429 Too Many Requests` are retried, after the delay
+/// given by the `Retry-After` header of the response.
+/// Requests streaming their body, like [`Index::add_documents_ndjson`](crate::indexes::Index::add_documents_ndjson), can't be replayed and are never retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Maximum number of times a request is retried. **Default = 3**
+    pub max_retries: u32,
+    /// Delay before retrying when the response has no valid `Retry-After` header. **Default = 1s**
+    pub default_delay: Duration,
+    /// Upper bound of the delay, whatever the `Retry-After` header says. **Default = 30s**
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_retries: 3,
+            default_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The default [`HttpClient`] of the SDK, backed by [`reqwest`].
+///
+/// A single [`reqwest::Client`] is built once and shared by every request, so its connection
+/// pool and keep-alive connections are reused. Cloning a `ReqwestClient` is cheap and shares
+/// the same pool: prefer building one [`Client`](crate::client::Client) and cloning it rather
+/// than creating a new one per request.
 #[derive(Debug, Clone, Default)]
 pub struct ReqwestClient {
     client: reqwest::Client,
+    #[cfg(feature = "compression")]
+    gzip: bool,
+    retry: Option<RetryConfig>,
 }
 
 impl ReqwestClient {
+    /// Build the underlying [`reqwest::Client`] with the SDK headers and the optional api key.
     pub fn new(api_key: Option<&str>) -> Result<Self, Error> {
+        Self::build(api_key, None, reqwest::header::HeaderMap::new())
+    }
+
+    /// Same as [`ReqwestClient::new`], but every request fails with [`Error::RequestTimeout`] if it takes longer than `timeout`.
+    ///
+    /// Use it with [`Client::new_with_client`](crate::client::Client::new_with_client).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, reqwest::ReqwestClient};
+    /// # use std::time::Duration;
+    /// #
+    /// let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    ///
+    /// let http_client = ReqwestClient::with_timeout(Some(MEILISEARCH_API_KEY), Duration::from_secs(5)).unwrap();
+    /// let client = Client::new_with_client(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY), http_client);
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_timeout(api_key: Option<&str>, timeout: Duration) -> Result<Self, Error> {
+        Self::build(api_key, Some(timeout), reqwest::header::HeaderMap::new())
+    }
+
+    /// Same as [`ReqwestClient::new`], but `headers` are also sent with every request, for example the key of an API gateway
+    /// standing in front of Meilisearch.
+    ///
+    /// The `Authorization` and `User-Agent` headers of the SDK are only replaced if `headers` explicitly contains them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, reqwest::ReqwestClient};
+    /// # use reqwest::header::{HeaderMap, HeaderValue};
+    /// #
+    /// let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("X-Api-Gateway-Key", HeaderValue::from_static("gateway-secret"));
+    ///
+    /// let http_client = ReqwestClient::with_default_headers(Some(MEILISEARCH_API_KEY), headers).unwrap();
+    /// let client = Client::new_with_client(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY), http_client);
+    /// ```
+    pub fn with_default_headers(
+        api_key: Option<&str>,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<Self, Error> {
+        Self::build(api_key, None, headers)
+    }
+
+    /// Compress the body of every request with gzip, and send it with the `Content-Encoding: gzip` header.
+    ///
+    /// Both the JSON bodies and the streamed payloads, like [`Index::add_documents_ndjson`](crate::indexes::Index::add_documents_ndjson),
+    /// are compressed. Requests without a body are left untouched.
+    ///
+    /// It saves a lot of bandwidth when uploading large batches of documents over a slow link, at the cost of some CPU.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, reqwest::ReqwestClient};
+    /// #
+    /// let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    ///
+    /// let http_client = ReqwestClient::new(Some(MEILISEARCH_API_KEY))
+    ///     .unwrap()
+    ///     .with_gzip_compression(true);
+    /// let client = Client::new_with_client(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY), http_client);
+    /// ```
+    #[cfg(all(feature = "compression", not(target_arch = "wasm32")))]
+    #[must_use]
+    pub fn with_gzip_compression(mut self, enabled: bool) -> Self {
+        self.gzip = enabled;
+        self
+    }
+
+    /// Retry the requests rejected with a `503` or a `429` status code, following the [`RetryConfig`].
+    ///
+    /// By default, these requests are not retried and return an error right away.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, reqwest::*};
+    /// #
+    /// let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    ///
+    /// let http_client = ReqwestClient::new(Some(MEILISEARCH_API_KEY))
+    ///     .unwrap()
+    ///     .with_retry(RetryConfig {
+    ///         max_retries: 5,
+    ///         ..RetryConfig::default()
+    ///     });
+    /// let client = Client::new_with_client(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY), http_client);
+    /// ```
+    #[must_use]
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    fn build(
+        api_key: Option<&str>,
+        timeout: Option<Duration>,
+        mut headers: reqwest::header::HeaderMap,
+    ) -> Result<Self, Error> {
         use reqwest::{header, ClientBuilder};
 
-        let builder = ClientBuilder::new();
-        let mut headers = header::HeaderMap::new();
+        #[allow(unused_mut)]
+        let mut builder = ClientBuilder::new();
         #[cfg(not(target_arch = "wasm32"))]
-        headers.insert(
-            header::USER_AGENT,
-            header::HeaderValue::from_str(&qualified_version()).unwrap(),
-        );
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
         #[cfg(target_arch = "wasm32")]
-        headers.insert(
-            header::HeaderName::from_static("x-meilisearch-client"),
-            header::HeaderValue::from_str(&qualified_version()).unwrap(),
-        );
+        let _ = timeout;
+
+        // The headers explicitly provided by the user take precedence over the ones of the SDK
+        #[cfg(not(target_arch = "wasm32"))]
+        headers
+            .entry(header::USER_AGENT)
+            .or_insert(header::HeaderValue::from_str(&qualified_version()).unwrap());
+        #[cfg(target_arch = "wasm32")]
+        headers
+            .entry(header::HeaderName::from_static("x-meilisearch-client"))
+            .or_insert(header::HeaderValue::from_str(&qualified_version()).unwrap());
 
         if let Some(api_key) = api_key {
-            headers.insert(
-                header::AUTHORIZATION,
-                header::HeaderValue::from_str(&format!("Bearer {api_key}")).unwrap(),
-            );
+            headers
+                .entry(header::AUTHORIZATION)
+                .or_insert(header::HeaderValue::from_str(&format!("Bearer {api_key}")).unwrap());
         }
 
         let builder = builder.default_headers(headers);
         let client = builder.build()?;
 
-        Ok(ReqwestClient { client })
+        Ok(ReqwestClient {
+            client,
+            #[cfg(feature = "compression")]
+            gzip: false,
+            retry: None,
+        })
     }
 }
 
@@ -66,23 +223,24 @@ impl HttpClient for ReqwestClient {
     ) -> Result<Output, Error> {
         use reqwest::header;
 
-        let query = method.query();
-        let query = yaup::to_string(query)?;
-
-        let url = if query.is_empty() {
-            url.to_string()
-        } else {
-            format!("{url}{query}")
-        };
-
-        let mut request = self.client.request(verb(&method), &url);
+        let (mut request, url) = self.request_builder(url, &method)?;
 
         if let Some(body) = method.into_body() {
             // TODO: Currently reqwest doesn't support streaming data in wasm so we need to collect everything in RAM
             #[cfg(not(target_arch = "wasm32"))]
             {
-                let stream = ReaderStream::new(body);
-                let body = reqwest::Body::wrap_stream(stream);
+                #[cfg(feature = "compression")]
+                let body = if self.gzip {
+                    use async_compression::futures::bufread::GzipEncoder;
+
+                    request = request.header(header::CONTENT_ENCODING, "gzip");
+                    let encoder = GzipEncoder::new(futures::io::BufReader::new(body));
+                    reqwest::Body::wrap_stream(ReaderStream::new(encoder))
+                } else {
+                    reqwest::Body::wrap_stream(ReaderStream::new(body))
+                };
+                #[cfg(not(feature = "compression"))]
+                let body = reqwest::Body::wrap_stream(ReaderStream::new(body));
 
                 request = request
                     .header(header::CONTENT_TYPE, content_type)
@@ -101,18 +259,178 @@ impl HttpClient for ReqwestClient {
             }
         }
 
-        let response = self.client.execute(request.build()?).await?;
+        self.send(request.build()?, url, expected_status_code)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn request<Query, Body, Output>(
+        &self,
+        url: &str,
+        method: Method<Query, Body>,
+        expected_status_code: u16,
+    ) -> Result<Output, Error>
+    where
+        Query: Serialize + Send + Sync,
+        Body: Serialize + Send + Sync,
+        Output: DeserializeOwned + 'static + Send,
+    {
+        self.request_with_headers(url, method, expected_status_code)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn request_with_headers<Query, Body, Output>(
+        &self,
+        url: &str,
+        method: Method<Query, Body>,
+        expected_status_code: u16,
+    ) -> Result<(Output, ResponseHeaders), Error>
+    where
+        Query: Serialize + Send + Sync,
+        Body: Serialize + Send + Sync,
+        Output: DeserializeOwned + 'static + Send,
+    {
+        use reqwest::header;
+
+        let (mut request, url) = self.request_builder(url, &method)?;
+
+        // Unlike the streamed payloads, the serialized body can be replayed if the request is retried
+        if let Some(body) = method.body() {
+            let body = serde_json::to_vec(body)?;
+            #[cfg(feature = "compression")]
+            let body = if self.gzip {
+                request = request.header(header::CONTENT_ENCODING, "gzip");
+                gzip(&body).await?
+            } else {
+                body
+            };
+
+            request = request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        self.send(request.build()?, url, expected_status_code).await
+    }
+}
+
+impl ReqwestClient {
+    fn request_builder<Q: Serialize, B>(
+        &self,
+        url: &str,
+        method: &Method<Q, B>,
+    ) -> Result<(reqwest::RequestBuilder, String), Error> {
+        let query = yaup::to_string(method.query())?;
+
+        let url = if query.is_empty() {
+            url.to_string()
+        } else {
+            format!("{url}{query}")
+        };
+
+        Ok((self.client.request(verb(method), &url), url))
+    }
+
+    async fn send<Output: DeserializeOwned>(
+        &self,
+        mut request: reqwest::Request,
+        url: String,
+        expected_status_code: u16,
+    ) -> Result<(Output, ResponseHeaders), Error> {
+        let mut retries = 0;
+        let response = loop {
+            let retry = match self.retry {
+                Some(retry) if retries < retry.max_retries => {
+                    request.try_clone().map(|clone| (retry, clone))
+                }
+                _ => None,
+            };
+
+            let response = self.client.execute(request).await.map_err(|err| {
+                if err.is_timeout() {
+                    Error::RequestTimeout
+                } else {
+                    Error::HttpError(err)
+                }
+            })?;
+
+            match retry {
+                Some((retry, clone)) if is_overloaded(response.status()) => {
+                    let delay = retry_after(&response)
+                        .unwrap_or(retry.default_delay)
+                        .min(retry.max_delay);
+                    warn!(
+                        "Meilisearch responded with a {}, retrying in {:?}",
+                        response.status().as_u16(),
+                        delay
+                    );
+                    async_sleep(delay).await;
+                    retries += 1;
+                    request = clone;
+                }
+                _ => break response,
+            }
+        };
+
         let status = response.status().as_u16();
-        let mut body = response.text().await?;
+        let headers = ResponseHeaders::new(
+            response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    let value = value.to_str().ok()?;
+                    Some((name.to_string(), value.to_string()))
+                })
+                .collect(),
+        );
+        let mut body = response.text().await.map_err(|err| {
+            if err.is_timeout() {
+                Error::RequestTimeout
+            } else {
+                Error::HttpError(err)
+            }
+        })?;
 
         if body.is_empty() {
             body = "null".to_string();
         }
 
-        parse_response(status, expected_status_code, &body, url.to_string())
+        parse_response(status, expected_status_code, &body, url).map(|output| (output, headers))
     }
 }
 
+#[cfg(feature = "compression")]
+async fn gzip(body: &[u8]) -> Result<Vec<u8>, Error> {
+    use async_compression::futures::bufread::GzipEncoder;
+    use futures::AsyncReadExt;
+
+    let mut compressed = Vec::new();
+    GzipEncoder::new(body)
+        .read_to_end(&mut compressed)
+        .await
+        .map_err(|err| Error::Other(Box::new(err)))?;
+    Ok(compressed)
+}
+
+fn is_overloaded(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+/// The delay of the `Retry-After` header, only supported when given in seconds.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
 fn verb<Q, B>(method: &Method<Q, B>) -> reqwest::Method {
     match method {
         Method::Get { .. } => reqwest::Method::GET,
@@ -171,3 +489,220 @@ impl<R: AsyncRead> Stream for ReaderStream<R> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use meilisearch_test_macro::meilisearch_test;
+
+    #[meilisearch_test]
+    async fn test_retry_on_overloaded_server() {
+        let mut s = mockito::Server::new_async().await;
+        let mock = s
+            .mock("GET", "/version")
+            .with_status(503)
+            .with_header("Retry-After", "0")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let http_client = ReqwestClient::new(None).unwrap().with_retry(RetryConfig {
+            max_retries: 2,
+            ..RetryConfig::default()
+        });
+        let client = Client::new_with_client(s.url(), None::<String>, http_client);
+
+        let error = client.get_version().await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnexpectedResponse {
+                status_code: 503,
+                ..
+            }
+        ));
+        mock.assert_async().await;
+    }
+
+    #[meilisearch_test]
+    async fn test_no_retry_by_default() {
+        let mut s = mockito::Server::new_async().await;
+        let mock = s
+            .mock("GET", "/version")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(s.url(), None::<String>).unwrap();
+
+        let error = client.get_version().await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnexpectedResponse {
+                status_code: 429,
+                ..
+            }
+        ));
+        mock.assert_async().await;
+    }
+
+    #[meilisearch_test]
+    async fn test_request_with_headers() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let mock = s
+            .mock("GET", "/version")
+            .with_status(200)
+            .with_header("X-RateLimit-Remaining", "41")
+            .with_body(r#"{"commitSha": "b46889b", "commitDate": "2019-11-15T09:51:54.278247+00:00", "pkgVersion": "1.0.0"}"#)
+            .create_async()
+            .await;
+
+        let http_client = ReqwestClient::new(None)?;
+        let (version, headers) = http_client
+            .request_with_headers::<(), (), crate::client::Version>(
+                &format!("{}/version", s.url()),
+                Method::Get { query: () },
+                200,
+            )
+            .await?;
+
+        assert_eq!(version.pkg_version, "1.0.0");
+        assert_eq!(headers.get("x-ratelimit-remaining"), Some("41"));
+        assert_eq!(headers.get("X-RateLimit-Reset"), None);
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_default_headers() -> Result<(), Error> {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut s = mockito::Server::new_async().await;
+        let mock = s
+            .mock("GET", "/version")
+            .match_header("x-api-gateway-key", "gateway-secret")
+            .match_header("authorization", "Bearer masterKey")
+            .match_header("user-agent", qualified_version().as_str())
+            .with_status(200)
+            .with_body(r#"{"commitSha": "b46889b", "commitDate": "2019-11-15T09:51:54.278247+00:00", "pkgVersion": "1.0.0"}"#)
+            .create_async()
+            .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Api-Gateway-Key",
+            HeaderValue::from_static("gateway-secret"),
+        );
+        let http_client = ReqwestClient::with_default_headers(Some("masterKey"), headers)?;
+        let client = Client::new_with_client(s.url(), Some("masterKey"), http_client);
+
+        client.get_version().await?;
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_default_headers_override_authorization() -> Result<(), Error> {
+        use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+
+        let mut s = mockito::Server::new_async().await;
+        let mock = s
+            .mock("GET", "/version")
+            .match_header("authorization", "Bearer gatewayToken")
+            .with_status(200)
+            .with_body(r#"{"commitSha": "b46889b", "commitDate": "2019-11-15T09:51:54.278247+00:00", "pkgVersion": "1.0.0"}"#)
+            .create_async()
+            .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("Bearer gatewayToken"),
+        );
+        let http_client = ReqwestClient::with_default_headers(Some("masterKey"), headers)?;
+        let client = Client::new_with_client(s.url(), Some("masterKey"), http_client);
+
+        client.get_version().await?;
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[meilisearch_test]
+    async fn test_gzip_compressed_json_body() -> Result<(), Error> {
+        use async_compression::futures::bufread::GzipDecoder;
+        use futures::AsyncReadExt;
+
+        let mut s = mockito::Server::new_async().await;
+        let mock = s
+            .mock("POST", "/indexes")
+            .match_header("content-encoding", "gzip")
+            .match_header("content-type", "application/json")
+            .match_request(|request| {
+                let body = request.body().unwrap();
+                let mut decompressed = Vec::new();
+                futures::executor::block_on(
+                    GzipDecoder::new(body.as_slice()).read_to_end(&mut decompressed),
+                )
+                .unwrap();
+                serde_json::from_slice::<serde_json::Value>(&decompressed).unwrap()
+                    == serde_json::json!({ "uid": "movies", "primaryKey": "id" })
+            })
+            .with_status(202)
+            .with_body(r#"{"taskUid": 1, "indexUid": "movies", "status": "enqueued", "type": "indexCreation", "enqueuedAt": "2024-01-01T00:00:00Z"}"#)
+            .create_async()
+            .await;
+
+        let http_client = ReqwestClient::new(None)?.with_gzip_compression(true);
+        let client = Client::new_with_client(s.url(), None::<String>, http_client);
+
+        client.create_index("movies", Some("id")).await?;
+        mock.assert_async().await;
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[meilisearch_test]
+    async fn test_gzip_compressed_documents(
+        client: Client,
+        index: crate::indexes::Index,
+    ) -> Result<(), Error> {
+        #[derive(Debug, serde::Deserialize, PartialEq)]
+        struct Document {
+            id: usize,
+            value: String,
+        }
+
+        let http_client =
+            ReqwestClient::new(client.api_key.as_deref())?.with_gzip_compression(true);
+        let gzip_client =
+            Client::new_with_client(&client.host, client.api_key.clone(), http_client);
+        let gzip_index = gzip_client.index(&index.uid);
+
+        let payload =
+            "{ \"id\": 1, \"value\": \"compressed\" }\n{ \"id\": 2, \"value\": \"payload\" }\n";
+        gzip_index
+            .add_documents_ndjson(payload.as_bytes(), Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let documents = index.get_documents::<Document>().await?;
+        assert_eq!(
+            documents.results,
+            vec![
+                Document {
+                    id: 1,
+                    value: "compressed".to_string(),
+                },
+                Document {
+                    id: 2,
+                    value: "payload".to_string(),
+                },
+            ]
+        );
+        Ok(())
+    }
+}
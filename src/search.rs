@@ -2,10 +2,18 @@ use crate::{
     client::Client, errors::Error, indexes::Index, request::HttpClient, DefaultHttpClient,
 };
 use either::Either;
+use futures::{stream, Stream, TryStreamExt};
 use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use serde_json::{Map, Value};
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+};
+
+/// Number of documents fetched per request by [`SearchQuery::execute_stream`].
+pub const SEARCH_STREAM_PAGE_SIZE: usize = 100;
 
+/// The position of a matched term in an attribute, in bytes.
 #[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct MatchRange {
     pub start: usize,
@@ -16,26 +24,430 @@ pub struct MatchRange {
 #[serde(transparent)]
 pub struct Filter<'a> {
     #[serde(with = "either::serde_untagged")]
-    inner: Either<&'a str, Vec<&'a str>>,
+    inner: Either<Cow<'a, str>, Vec<&'a str>>,
 }
 
 impl<'a> Filter<'a> {
     #[must_use]
     pub fn new(inner: Either<&'a str, Vec<&'a str>>) -> Filter<'a> {
-        Filter { inner }
+        Filter {
+            inner: inner.map_left(Cow::Borrowed),
+        }
+    }
+}
+
+impl From<&FilterExpression> for Filter<'_> {
+    fn from(expression: &FilterExpression) -> Self {
+        Filter {
+            inner: Either::Left(Cow::Owned(expression.to_string())),
+        }
+    }
+}
+
+/// Keywords of the filter syntax that can't be used as bare attribute names.
+const FILTER_KEYWORDS: &[&str] = &[
+    "and", "or", "not", "to", "in", "exists", "is", "null", "empty", "contains", "starts", "_geo",
+];
+
+/// Escape an attribute name so it can be used on the left side of a [filter expression](https://www.meilisearch.com/docs/learn/filtering_and_sorting/filter_expression_reference).
+///
+/// Names colliding with a keyword of the filter syntax (`AND`, `TO`, `NOT`...) or containing special characters are wrapped in double quotes.
+/// To stay on the safe side, names starting with a keyword (like `order`) are quoted too, which Meilisearch accepts for any attribute.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::search::escape_filter_attribute;
+/// assert_eq!(escape_filter_attribute("genre"), "genre");
+/// assert_eq!(escape_filter_attribute("to"), "\"to\"");
+/// assert_eq!(escape_filter_attribute("release date"), "\"release date\"");
+///
+/// let filter = format!("{} > 3", escape_filter_attribute("order"));
+/// assert_eq!(filter, "\"order\" > 3");
+/// ```
+#[must_use]
+pub fn escape_filter_attribute(attribute: &str) -> Cow<'_, str> {
+    let lowercase = attribute.to_lowercase();
+    let is_plain = !attribute.is_empty()
+        && attribute
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !FILTER_KEYWORDS
+            .iter()
+            .any(|keyword| lowercase.starts_with(keyword));
+
+    if is_plain {
+        Cow::Borrowed(attribute)
+    } else {
+        Cow::Owned(format!(
+            "\"{}\"",
+            attribute.replace('\\', "\\\\").replace('"', "\\\"")
+        ))
+    }
+}
+
+/// A value compared to an attribute in a [`FilterExpression`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    String(String),
+    /// An integer, kept exact instead of being rounded to a float.
+    Integer(i128),
+    /// A finite float.
+    Number(f64),
+    Bool(bool),
+}
+
+impl std::fmt::Display for FilterValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilterValue::String(value) => write!(
+                f,
+                "\"{}\"",
+                value.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+            FilterValue::Integer(value) => write!(f, "{value}"),
+            FilterValue::Number(value) => write!(f, "{value}"),
+            FilterValue::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::String(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::String(value)
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        FilterValue::Bool(value)
+    }
+}
+
+macro_rules! impl_integer_filter_value {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for FilterValue {
+                fn from(value: $integer) -> Self {
+                    FilterValue::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_filter_value!(i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+impl From<f32> for FilterValue {
+    /// # Panics
+    ///
+    /// Panics if `value` is NaN or infinite, which Meilisearch filters can't represent.
+    fn from(value: f32) -> Self {
+        FilterValue::from(f64::from(value))
+    }
+}
+
+impl From<f64> for FilterValue {
+    /// # Panics
+    ///
+    /// Panics if `value` is NaN or infinite, which Meilisearch filters can't represent.
+    fn from(value: f64) -> Self {
+        assert!(
+            value.is_finite(),
+            "filter values must be finite numbers, got {}",
+            value
+        );
+        FilterValue::Number(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FilterNode {
+    Condition(String),
+    And(Vec<FilterNode>),
+    Or(Vec<FilterNode>),
+    Not(Box<FilterNode>),
+}
+
+impl std::fmt::Display for FilterNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let write_operands =
+            |f: &mut std::fmt::Formatter<'_>, operands: &[FilterNode], operator: &str| {
+                for (i, operand) in operands.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " {operator} ")?;
+                    }
+                    match operand {
+                        FilterNode::And(_) | FilterNode::Or(_) => write!(f, "({operand})")?,
+                        _ => write!(f, "{operand}")?,
+                    }
+                }
+                Ok(())
+            };
+
+        match self {
+            FilterNode::Condition(condition) => f.write_str(condition),
+            FilterNode::And(operands) => write_operands(f, operands, "AND"),
+            FilterNode::Or(operands) => write_operands(f, operands, "OR"),
+            FilterNode::Not(operand) => match **operand {
+                FilterNode::Condition(_) | FilterNode::Not(_) => write!(f, "NOT {operand}"),
+                _ => write!(f, "NOT ({operand})"),
+            },
+        }
+    }
+}
+
+/// A typed [filter expression](https://www.meilisearch.com/docs/learn/filtering_and_sorting/filter_expression_reference), rendered to the Meilisearch filter syntax.
+///
+/// Attribute names are escaped with [`escape_filter_attribute`] and string values are quoted, so they can safely come from user input.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::search::FilterExpression;
+/// let filter = FilterExpression::eq("genre", "Action")
+///     .and(FilterExpression::gt("rating", 4.0))
+///     .and(!FilterExpression::in_list("director", ["Michael \"Bay\""]));
+///
+/// assert_eq!(
+///     filter.to_string(),
+///     r#"genre = "Action" AND rating > 4 AND NOT director IN ["Michael \"Bay\""]"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterExpression {
+    node: FilterNode,
+}
+
+impl FilterExpression {
+    fn comparison(attribute: &str, operator: &str, value: impl Into<FilterValue>) -> Self {
+        FilterExpression::condition(format!(
+            "{} {operator} {}",
+            escape_filter_attribute(attribute),
+            value.into()
+        ))
+    }
+
+    fn condition(condition: String) -> Self {
+        FilterExpression {
+            node: FilterNode::Condition(condition),
+        }
+    }
+
+    /// Match documents whose `attribute` is equal to `value`.
+    pub fn eq(attribute: &str, value: impl Into<FilterValue>) -> Self {
+        FilterExpression::comparison(attribute, "=", value)
+    }
+
+    /// Match documents whose `attribute` is not equal to `value`.
+    pub fn ne(attribute: &str, value: impl Into<FilterValue>) -> Self {
+        FilterExpression::comparison(attribute, "!=", value)
+    }
+
+    /// Match documents whose `attribute` is greater than `value`.
+    pub fn gt(attribute: &str, value: impl Into<FilterValue>) -> Self {
+        FilterExpression::comparison(attribute, ">", value)
+    }
+
+    /// Match documents whose `attribute` is greater than or equal to `value`.
+    pub fn gte(attribute: &str, value: impl Into<FilterValue>) -> Self {
+        FilterExpression::comparison(attribute, ">=", value)
+    }
+
+    /// Match documents whose `attribute` is lower than `value`.
+    pub fn lt(attribute: &str, value: impl Into<FilterValue>) -> Self {
+        FilterExpression::comparison(attribute, "<", value)
+    }
+
+    /// Match documents whose `attribute` is lower than or equal to `value`.
+    pub fn lte(attribute: &str, value: impl Into<FilterValue>) -> Self {
+        FilterExpression::comparison(attribute, "<=", value)
+    }
+
+    /// Match documents whose `attribute` is between `from` and `to`, both included.
+    pub fn to(attribute: &str, from: impl Into<FilterValue>, to: impl Into<FilterValue>) -> Self {
+        FilterExpression::condition(format!(
+            "{} {} TO {}",
+            escape_filter_attribute(attribute),
+            from.into(),
+            to.into()
+        ))
+    }
+
+    /// Match documents whose `attribute` is equal to one of `values`.
+    pub fn in_list<V: Into<FilterValue>>(
+        attribute: &str,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| value.into().to_string())
+            .collect::<Vec<_>>();
+        FilterExpression::condition(format!(
+            "{} IN [{}]",
+            escape_filter_attribute(attribute),
+            values.join(", ")
+        ))
+    }
+
+    /// Match documents containing `attribute`, whatever its value.
+    pub fn exists(attribute: &str) -> Self {
+        FilterExpression::condition(format!("{} EXISTS", escape_filter_attribute(attribute)))
+    }
+
+    /// Match documents whose `attribute` is `null`.
+    pub fn is_null(attribute: &str) -> Self {
+        FilterExpression::condition(format!("{} IS NULL", escape_filter_attribute(attribute)))
+    }
+
+    /// Match documents whose `attribute` is an empty string, array or object.
+    pub fn is_empty(attribute: &str) -> Self {
+        FilterExpression::condition(format!("{} IS EMPTY", escape_filter_attribute(attribute)))
+    }
+
+    /// Match documents whose `_geo` position is within `distance_in_meters` of the given point.
+    pub fn geo_radius(lat: f64, lng: f64, distance_in_meters: f64) -> Self {
+        FilterExpression::condition(format!("_geoRadius({lat}, {lng}, {distance_in_meters})"))
+    }
+
+    /// Match documents whose `_geo` position is within the box delimited by its top right and bottom left corners, as `(lat, lng)`.
+    pub fn geo_bounding_box(top_right: (f64, f64), bottom_left: (f64, f64)) -> Self {
+        FilterExpression::condition(format!(
+            "_geoBoundingBox([{}, {}], [{}, {}])",
+            top_right.0, top_right.1, bottom_left.0, bottom_left.1
+        ))
+    }
+
+    /// Match documents matching both `self` and `other`.
+    #[must_use]
+    pub fn and(self, other: FilterExpression) -> Self {
+        let node = match (self.node, other.node) {
+            (FilterNode::And(mut left), FilterNode::And(right)) => {
+                left.extend(right);
+                FilterNode::And(left)
+            }
+            (FilterNode::And(mut left), right) => {
+                left.push(right);
+                FilterNode::And(left)
+            }
+            (left, right) => FilterNode::And(vec![left, right]),
+        };
+        FilterExpression { node }
+    }
+
+    /// Match documents matching `self`, `other`, or both.
+    #[must_use]
+    pub fn or(self, other: FilterExpression) -> Self {
+        let node = match (self.node, other.node) {
+            (FilterNode::Or(mut left), FilterNode::Or(right)) => {
+                left.extend(right);
+                FilterNode::Or(left)
+            }
+            (FilterNode::Or(mut left), right) => {
+                left.push(right);
+                FilterNode::Or(left)
+            }
+            (left, right) => FilterNode::Or(vec![left, right]),
+        };
+        FilterExpression { node }
+    }
+}
+
+impl std::ops::Not for FilterExpression {
+    type Output = FilterExpression;
+
+    fn not(self) -> Self::Output {
+        FilterExpression {
+            node: FilterNode::Not(Box::new(self.node)),
+        }
+    }
+}
+
+impl std::fmt::Display for FilterExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.node.fmt(f)
+    }
+}
+
+/// A position on earth, as expected by Meilisearch in the `_geo` field of the documents.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::search::GeoPoint;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Restaurant {
+///     id: usize,
+///     name: String,
+///     #[serde(rename = "_geo")]
+///     geo: GeoPoint,
+/// }
+///
+/// let restaurant = Restaurant {
+///     id: 1,
+///     name: String::from("Nàpiz' Milano"),
+///     geo: GeoPoint::new(45.4777599, 9.1967508),
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&restaurant).unwrap()["_geo"],
+///     serde_json::json!({ "lat": 45.4777599, "lng": 9.1967508 })
+/// );
+/// assert_eq!(restaurant.geo.sort_rule(true), "_geoPoint(45.4777599, 9.1967508):asc");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+impl GeoPoint {
+    #[must_use]
+    pub fn new(lat: f64, lng: f64) -> GeoPoint {
+        GeoPoint { lat, lng }
     }
+
+    /// The rule sorting the documents by their distance to this point, to use with [`SearchQuery::with_sort`].
+    #[must_use]
+    pub fn sort_rule(&self, ascending: bool) -> String {
+        let order = if ascending { "asc" } else { "desc" };
+        format!("_geoPoint({}, {}):{order}", self.lat, self.lng)
+    }
+}
+
+/// Defines how Meilisearch handles queries containing multiple words.
+///
+/// See [the documentation](https://www.meilisearch.com/docs/reference/api/search#matching-strategy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchingStrategy {
+    /// Only return documents containing all query terms.
+    All,
+    /// Return documents containing all query terms first, then progressively drop the last terms.
+    Last,
+    /// Return documents containing all query terms first, then progressively drop the most frequent terms.
+    Frequency,
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub enum MatchingStrategies {
-    #[serde(rename = "all")]
-    ALL,
-    #[serde(rename = "last")]
-    LAST,
-    #[serde(rename = "frequency")]
-    FREQUENCY,
+impl MatchingStrategy {
+    #[deprecated(note = "use `MatchingStrategy::All` instead")]
+    pub const ALL: MatchingStrategy = MatchingStrategy::All;
+    #[deprecated(note = "use `MatchingStrategy::Last` instead")]
+    pub const LAST: MatchingStrategy = MatchingStrategy::Last;
+    #[deprecated(note = "use `MatchingStrategy::Frequency` instead")]
+    pub const FREQUENCY: MatchingStrategy = MatchingStrategy::Frequency;
 }
 
+#[deprecated(note = "renamed to `MatchingStrategy`")]
+pub type MatchingStrategies = MatchingStrategy;
+
 /// A single result.
 ///
 /// Contains the complete object, optionally the formatted object, and optionally an object that contains information about the matches.
@@ -53,10 +465,113 @@ pub struct SearchResult<T> {
     /// The relevancy score of the match.
     #[serde(rename = "_rankingScore")]
     pub ranking_score: Option<f64>,
+    /// The detailed score of each ranking rule, as returned by Meilisearch.
+    ///
+    /// See [`SearchResult::typed_ranking_score_details`] for a typed view.
     #[serde(rename = "_rankingScoreDetails")]
     pub ranking_score_details: Option<Map<String, Value>>,
+    /// Where the hit comes from, only present in [federated](MultiSearchQuery::with_federation) search results.
+    #[serde(rename = "_federation")]
+    pub federation: Option<FederationHitInfo>,
+    /// The embeddings stored for the document, by embedder name.
+    ///
+    /// Only present when requested with [`SearchQuery::with_retrieve_vectors`].
+    /// The `_vectors` key is always read into this field, so a `_vectors` field of `T` stays empty even when it is returned.
+    #[serde(rename = "_vectors")]
+    pub vectors: Option<Map<String, Value>>,
+    /// The distance in meters between the document and the point used to sort the results.
+    ///
+    /// Only present when sorting on a [`_geoPoint`](GeoPoint::sort_rule).
+    /// A `_geoDistance` field declared on `T` is never filled, Meilisearch's value ends up here.
+    #[serde(rename = "_geoDistance")]
+    pub geo_distance: Option<f64>,
+}
+
+/// Information about the origin of a hit in a [federated](MultiSearchQuery::with_federation) search.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FederationHitInfo {
+    /// The index the hit comes from.
+    pub index_uid: String,
+    /// The position of the query that matched the hit in the multi-search request.
+    pub queries_position: usize,
+    /// The ranking score of the hit, weighted by the federation options of its query.
+    pub weighted_ranking_score: f64,
+}
+
+impl<T> SearchResult<T> {
+    /// Parse the [ranking score details](SearchResult::ranking_score_details) into a [`RankingScoreDetails`].
+    ///
+    /// Returns `Ok(None)` if the details were not requested with [`SearchQuery::with_show_ranking_score_details`].
+    pub fn typed_ranking_score_details(&self) -> Result<Option<RankingScoreDetails>, Error> {
+        self.ranking_score_details
+            .as_ref()
+            .map(|details| serde_json::from_value(Value::Object(details.clone())))
+            .transpose()
+            .map_err(Error::ParseError)
+    }
+}
+
+/// The per-rule breakdown of the ranking score of a [`SearchResult`].
+///
+/// The built-in ranking rules are typed.
+/// Sort rules (keyed as `attribute:asc` or `attribute:desc`), custom rules, and rules unknown to this version of the SDK are kept in [`other`](RankingScoreDetails::other).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RankingScoreDetails {
+    pub words: Option<WordsScoreDetails>,
+    pub typo: Option<TypoScoreDetails>,
+    pub proximity: Option<ProximityScoreDetails>,
+    pub attribute: Option<AttributeScoreDetails>,
+    pub exactness: Option<ExactnessScoreDetails>,
+    /// The details of the remaining ranking rules.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WordsScoreDetails {
+    pub order: usize,
+    pub matching_words: usize,
+    pub max_matching_words: usize,
+    pub score: f64,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypoScoreDetails {
+    pub order: usize,
+    pub typo_count: usize,
+    pub max_typo_count: usize,
+    pub score: f64,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProximityScoreDetails {
+    pub order: usize,
+    pub score: f64,
 }
 
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeScoreDetails {
+    pub order: usize,
+    pub attribute_ranking_order_score: f64,
+    pub query_word_distance_score: f64,
+    pub score: f64,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExactnessScoreDetails {
+    pub order: usize,
+    pub match_type: String,
+    pub score: f64,
+}
+
+/// The minimum and maximum values of a numeric facet among the matching documents.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FacetStats {
@@ -75,18 +590,27 @@ pub struct SearchResults<T> {
     /// Number of results returned.
     pub limit: Option<usize>,
     /// Estimated total number of matches.
+    ///
+    /// Only present when paginating with `offset` and `limit`, see [`SearchResults::hit_count`].
     pub estimated_total_hits: Option<usize>,
     // Current page number
     pub page: Option<usize>,
     // Maximum number of hits in a page.
     pub hits_per_page: Option<usize>,
-    // Exhaustive number of matches.
+    /// Exhaustive number of matches.
+    ///
+    /// Only present when paginating with `page` and `hits_per_page`, see [`SearchResults::hit_count`].
     pub total_hits: Option<usize>,
     // Exhaustive number of pages.
     pub total_pages: Option<usize>,
-    /// Distribution of the given facets.
+    /// Distribution of the given facets: the number of matching documents for each value of each facet.
+    ///
+    /// `None` unless facets were requested with [`SearchQuery::with_facets`].
+    /// Each facet holds at most `maxValuesPerFacet` values, see [`SearchQuery::with_facets`].
     pub facet_distribution: Option<HashMap<String, HashMap<String, usize>>>,
-    /// facet stats of the numerical facets requested in the `facet` search parameter.
+    /// Facet stats of the numerical facets requested in the `facet` search parameter.
+    ///
+    /// Facets without any numeric value among the matching documents are omitted.
     pub facet_stats: Option<HashMap<String, FacetStats>>,
     /// Processing time of the query.
     pub processing_time_ms: usize,
@@ -96,7 +620,147 @@ pub struct SearchResults<T> {
     pub index_uid: Option<String>,
 }
 
-fn serialize_with_wildcard<S: Serializer, T: Serialize>(
+impl<T> SearchResults<T> {
+    /// The number of documents matching the query, whatever the pagination mode.
+    ///
+    /// Meilisearch returns an [`estimated_total_hits`](SearchResults::estimated_total_hits) when paginating with `offset` and `limit`,
+    /// and an exhaustive [`total_hits`](SearchResults::total_hits) when paginating with [`page`](SearchQuery::with_page) and [`hits_per_page`](SearchQuery::with_hits_per_page).
+    /// This returns the exhaustive count if present, the estimation otherwise.
+    #[must_use]
+    pub fn hit_count(&self) -> Option<usize> {
+        self.total_hits.or(self.estimated_total_hits)
+    }
+
+    /// A one-line, human-readable summary of the results for logging, like `3 hits (estimated 42) in 1ms for query 'wonder'`.
+    ///
+    /// Same as the [`Display`](std::fmt::Display) implementation of the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::search::SearchResults;
+    /// # use serde_json::{json, Value};
+    /// let results: SearchResults<Value> = serde_json::from_value(json!({
+    ///     "hits": [{ "id": 1 }, { "id": 2 }],
+    ///     "estimatedTotalHits": 42,
+    ///     "processingTimeMs": 3,
+    ///     "query": "wonder",
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(results.summary(), "2 hits (estimated 42) in 3ms for query 'wonder'");
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T> std::fmt::Display for SearchResults<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.hits.len() {
+            1 => write!(f, "1 hit")?,
+            hits => write!(f, "{hits} hits")?,
+        }
+        if let Some(total_hits) = self.total_hits {
+            write!(f, " (total {total_hits})")?;
+        } else if let Some(estimated_total_hits) = self.estimated_total_hits {
+            write!(f, " (estimated {estimated_total_hits})")?;
+        }
+        write!(
+            f,
+            " in {}ms for query '{}'",
+            self.processing_time_ms, self.query
+        )
+    }
+}
+
+impl SearchResults<Value> {
+    /// Deserialize the hits of untyped search results into `T`.
+    ///
+    /// This is useful with [multi-search](MultiSearchQuery), where each query can target an index containing a different type of documents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Actor {
+    ///     id: usize,
+    ///     full_name: String,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movies = client.index("into_typed_movies");
+    /// let actors = client.index("into_typed_actors");
+    /// # movies.add_documents(&[Movie { name: String::from("Interstellar") }], Some("name")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # actors.add_documents(&[Actor { id: 0, full_name: String::from("Matthew McConaughey") }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let response = client
+    ///     .multi_search()
+    ///     .with_search_query(movies.search().build())
+    ///     .with_search_query(actors.search().build())
+    ///     .execute::<serde_json::Value>()
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let mut results = response.results.into_iter();
+    /// let movies_results = results.next().unwrap().into_typed::<Movie>().unwrap();
+    /// let actors_results = results.next().unwrap().into_typed::<Actor>().unwrap();
+    ///
+    /// assert_eq!(actors_results.hits[0].result.full_name, "Matthew McConaughey");
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # actors.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub fn into_typed<T: DeserializeOwned>(self) -> Result<SearchResults<T>, Error> {
+        let hits = self
+            .hits
+            .into_iter()
+            .map(|hit| {
+                Ok(SearchResult {
+                    result: serde_json::from_value(hit.result)?,
+                    formatted_result: hit.formatted_result,
+                    matches_position: hit.matches_position,
+                    ranking_score: hit.ranking_score,
+                    ranking_score_details: hit.ranking_score_details,
+                    federation: hit.federation,
+                    vectors: hit.vectors,
+                    geo_distance: hit.geo_distance,
+                })
+            })
+            .collect::<Result<_, serde_json::Error>>()?;
+
+        Ok(SearchResults {
+            hits,
+            offset: self.offset,
+            limit: self.limit,
+            estimated_total_hits: self.estimated_total_hits,
+            page: self.page,
+            hits_per_page: self.hits_per_page,
+            total_hits: self.total_hits,
+            total_pages: self.total_pages,
+            facet_distribution: self.facet_distribution,
+            facet_stats: self.facet_stats,
+            processing_time_ms: self.processing_time_ms,
+            query: self.query,
+            index_uid: self.index_uid,
+        })
+    }
+}
+
+pub(crate) fn serialize_with_wildcard<S: Serializer, T: Serialize>(
     data: &Option<Selectors<T>>,
     s: S,
 ) -> Result<S::Ok, S::Error> {
@@ -208,6 +872,9 @@ pub struct SearchQuery<'a, Http: HttpClient> {
     #[serde(skip_serializing)]
     index: &'a Index<Http>,
     /// The text that will be searched for among the documents.
+    ///
+    /// `Some("")` is sent as `"q": ""` while `None` omits `q`: both run a [placeholder search](https://www.meilisearch.com/docs/reference/api/search#placeholder-search)
+    /// returning every document matching the filters, sorted by the ranking rules.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "q")]
     pub query: Option<&'a str>,
@@ -248,9 +915,9 @@ pub struct SearchQuery<'a, Http: HttpClient> {
     pub filter: Option<Filter<'a>>,
     /// Facets for which to retrieve the matching count.
     ///
-    /// Can be set to a [wildcard value](enum.Selectors.html#variant.All) that will select all existing attributes.
+    /// Can be set to a [wildcard value](enum.Selectors.html#variant.All) that will select all the filterable attributes.
     ///
-    /// **Default: all attributes found in the documents.**
+    /// **Default: no facet distribution is returned.**
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(serialize_with = "serialize_with_wildcard")]
     pub facets: Option<Selectors<&'a [&'a str]>>,
@@ -334,7 +1001,7 @@ pub struct SearchQuery<'a, Http: HttpClient> {
 
     /// Defines the strategy on how to handle queries containing multiple words.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub matching_strategy: Option<MatchingStrategies>,
+    pub matching_strategy: Option<MatchingStrategy>,
 
     ///Defines one attribute in the filterableAttributes list as a distinct attribute.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -347,9 +1014,27 @@ pub struct SearchQuery<'a, Http: HttpClient> {
     /// Defines the language of the search query.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locales: Option<&'a [&'a str]>,
+    /// Return the embeddings of the documents in the `_vectors` field of the hits.
+    ///
+    /// **Default: `false`**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrieve_vectors: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) index_uid: Option<&'a str>,
+
+    /// Check that every attribute of [sort](#structfield.sort) is sortable before sending the query.
+    ///
+    /// Unless [sortable_attributes](#structfield.sortable_attributes) are provided, this costs an extra request
+    /// per search to fetch the sortable attributes of the index.
+    ///
+    /// **Default: `false`**
+    #[serde(skip_serializing)]
+    pub validate_sort: bool,
+
+    /// The sortable attributes of the index used to [validate the sort](#structfield.validate_sort) instead of fetching them.
+    #[serde(skip_serializing)]
+    pub sortable_attributes: Option<&'a [&'a str]>,
 }
 
 #[allow(missing_docs)]
@@ -382,8 +1067,32 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
             distinct: None,
             ranking_score_threshold: None,
             locales: None,
+            retrieve_vectors: None,
+            validate_sort: false,
+            sortable_attributes: None,
         }
     }
+    /// Search for `query` among the documents.
+    ///
+    /// An empty `query` is sent as is, as `"q": ""`, and runs a placeholder search returning all the documents in ranked order,
+    /// like a query on which `with_query` was never called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # let index = client.index("search_with_empty_query");
+    /// let placeholder = SearchQuery::new(&index).with_query("").build();
+    /// assert_eq!(serde_json::to_value(&placeholder).unwrap()["q"], "");
+    ///
+    /// let without_query = SearchQuery::new(&index).build();
+    /// assert!(serde_json::to_value(&without_query).unwrap().get("q").is_none());
+    /// ```
     pub fn with_query<'b>(&'b mut self, query: &'a str) -> &'b mut SearchQuery<'a, Http> {
         self.query = Some(query);
         self
@@ -399,6 +1108,9 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
     }
     /// Add the page number on which to paginate.
     ///
+    /// Setting `page` or `hits_per_page` switches to the finite pagination mode, returning `total_hits` and `total_pages`.
+    /// `offset` and `limit` are then not sent to Meilisearch.
+    ///
     /// # Example
     ///
     /// ```
@@ -474,21 +1186,232 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.filter = Some(Filter::new(Either::Right(filter)));
         self
     }
-    pub fn with_facets<'b>(
+    /// Filter the documents with a typed [`FilterExpression`] instead of a raw string.
+    pub fn with_filter_expr<'b>(
         &'b mut self,
-        facets: Selectors<&'a [&'a str]>,
+        filter: &FilterExpression,
     ) -> &'b mut SearchQuery<'a, Http> {
-        self.facets = Some(facets);
-        self
-    }
-    pub fn with_sort<'b>(&'b mut self, sort: &'a [&'a str]) -> &'b mut SearchQuery<'a, Http> {
-        self.sort = Some(sort);
+        self.filter = Some(filter.into());
         self
     }
-
-    pub fn with_attributes_to_search_on<'b>(
+    /// Only return the documents whose `_geo` position is within `distance_in_meters` of the given point.
+    ///
+    /// This replaces any filter previously set, combine a [`FilterExpression::geo_radius`] with [`SearchQuery::with_filter_expr`] to filter on other attributes too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = client.index("search_query_with_geo_radius");
+    /// let mut query = SearchQuery::new(&index);
+    ///
+    /// query.with_geo_radius(45.472735, 9.184019, 2000.0);
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&query).unwrap()["filter"],
+    ///     "_geoRadius(45.472735, 9.184019, 2000)"
+    /// );
+    /// ```
+    pub fn with_geo_radius<'b>(
         &'b mut self,
-        attributes_to_search_on: &'a [&'a str],
+        lat: f64,
+        lng: f64,
+        distance_in_meters: f64,
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.with_filter_expr(&FilterExpression::geo_radius(lat, lng, distance_in_meters))
+    }
+    /// Only return the documents whose `_geo` position is within the box delimited by its top right and bottom left corners, as `(lat, lng)`.
+    ///
+    /// This replaces any filter previously set, see [`SearchQuery::with_geo_radius`].
+    pub fn with_geo_bounding_box<'b>(
+        &'b mut self,
+        top_right: (f64, f64),
+        bottom_left: (f64, f64),
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.with_filter_expr(&FilterExpression::geo_bounding_box(top_right, bottom_left))
+    }
+    /// Remove the filter previously set with [`SearchQuery::with_filter`] or [`SearchQuery::with_array_filter`].
+    pub fn clear_filter<'b>(&'b mut self) -> &'b mut SearchQuery<'a, Http> {
+        self.filter = None;
+        self
+    }
+    /// Request the [facet distribution](SearchResults::facet_distribution) of the given attributes, or of every filterable attribute with [`Selectors::All`].
+    ///
+    /// Only the first values of each facet are counted, up to the [`max_values_per_facet`](crate::settings::FacetingSettings::max_values_per_facet)
+    /// setting of the index (100 by default), so the distribution of a facet with many distinct values is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = client.index("search_query_with_facets");
+    /// let mut query = SearchQuery::new(&index);
+    ///
+    /// query.with_facets(Selectors::All);
+    /// assert_eq!(serde_json::to_value(&query).unwrap()["facets"], serde_json::json!(["*"]));
+    ///
+    /// query.with_facets(Selectors::Some(&["genres", "director"]));
+    /// assert_eq!(
+    ///     serde_json::to_value(&query).unwrap()["facets"],
+    ///     serde_json::json!(["genres", "director"])
+    /// );
+    /// ```
+    pub fn with_facets<'b>(
+        &'b mut self,
+        facets: Selectors<&'a [&'a str]>,
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.facets = Some(facets);
+        self
+    }
+    /// Remove the facets previously set with [`SearchQuery::with_facets`].
+    pub fn clear_facets<'b>(&'b mut self) -> &'b mut SearchQuery<'a, Http> {
+        self.facets = None;
+        self
+    }
+    pub fn with_sort<'b>(&'b mut self, sort: &'a [&'a str]) -> &'b mut SearchQuery<'a, Http> {
+        self.sort = Some(sort);
+        self
+    }
+    /// Remove the sort previously set with [`SearchQuery::with_sort`].
+    pub fn clear_sort<'b>(&'b mut self) -> &'b mut SearchQuery<'a, Http> {
+        self.sort = None;
+        self
+    }
+    /// Check that the attributes used in [`SearchQuery::with_sort`] are sortable before sending the query.
+    ///
+    /// If some of them are not, [`Error::UnsortableAttributes`] is returned without searching.
+    ///
+    /// The sortable attributes of the index are fetched with an extra request every time the query is executed,
+    /// and once per [stream](SearchQuery::execute_stream). When the query is executed often, provide them with
+    /// [`SearchQuery::with_sortable_attributes`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, errors::Error, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #     name: String,
+    /// #     description: String,
+    /// # }
+    /// # client.create_index("search_with_sort_validation", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("search_with_sort_validation");
+    ///
+    /// let error = index
+    ///     .search()
+    ///     .with_sort(&["release_date:desc"])
+    ///     .with_sort_validation(true)
+    ///     .execute::<Movie>()
+    ///     .await
+    ///     .unwrap_err();
+    ///
+    /// assert!(matches!(error, Error::UnsortableAttributes(_)));
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub fn with_sort_validation<'b>(
+        &'b mut self,
+        validate_sort: bool,
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.validate_sort = validate_sort;
+        self
+    }
+
+    /// Check that the attributes used in [`SearchQuery::with_sort`] are part of `sortable_attributes` before sending the query,
+    /// without fetching the sortable attributes of the index.
+    ///
+    /// If some of them are not, [`Error::UnsortableAttributes`] is returned without searching.
+    /// Keeping `sortable_attributes` in sync with the settings of the index is up to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, errors::Error, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #     name: String,
+    /// #     description: String,
+    /// # }
+    /// let index = client.index("search_with_sortable_attributes");
+    ///
+    /// let error = index
+    ///     .search()
+    ///     .with_sort(&["release_date:desc"])
+    ///     .with_sortable_attributes(&["name"])
+    ///     .execute::<Movie>()
+    ///     .await
+    ///     .unwrap_err();
+    ///
+    /// assert!(matches!(error, Error::UnsortableAttributes(_)));
+    /// # });
+    /// ```
+    pub fn with_sortable_attributes<'b>(
+        &'b mut self,
+        sortable_attributes: &'a [&'a str],
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.validate_sort = true;
+        self.sortable_attributes = Some(sortable_attributes);
+        self
+    }
+
+    /// Restrict the search to a subset of the searchable attributes of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #     name: String,
+    /// #     description: String,
+    /// # }
+    /// # client.create_index("search_with_attributes_to_search_on", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("search_with_attributes_to_search_on");
+    ///
+    /// let res = index
+    ///     .search()
+    ///     .with_query("space")
+    ///     .with_attributes_to_search_on(&["name"])
+    ///     .execute::<Movie>()
+    ///     .await
+    ///     .unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub fn with_attributes_to_search_on<'b>(
+        &'b mut self,
+        attributes_to_search_on: &'a [&'a str],
     ) -> &'b mut SearchQuery<'a, Http> {
         self.attributes_to_search_on = Some(attributes_to_search_on);
         self
@@ -500,6 +1423,32 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.attributes_to_retrieve = Some(attributes_to_retrieve);
         self
     }
+    /// Crop the values of these attributes around the matched terms, each pair being an attribute and its own crop length.
+    ///
+    /// An attribute with a `None` length is cropped at the [`crop_length`](SearchQuery::with_crop_length) of the query.
+    /// Each pair is sent with the `attribute:length` syntax of Meilisearch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # let index = client.index("search_with_attributes_to_crop");
+    /// let query = SearchQuery::new(&index)
+    ///     .with_query("space")
+    ///     .with_attributes_to_crop(Selectors::Some(&[("overview", Some(20)), ("title", None)]))
+    ///     .with_crop_length(5)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&query).unwrap()["attributesToCrop"],
+    ///     serde_json::json!(["overview:20", "title"])
+    /// );
+    /// ```
     pub fn with_attributes_to_crop<'b>(
         &'b mut self,
         attributes_to_crop: Selectors<&'a [(&'a str, Option<usize>)]>,
@@ -511,6 +1460,7 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.crop_length = Some(crop_length);
         self
     }
+    /// Replace the `…` marker inserted where a cropped attribute was cut.
     pub fn with_crop_marker<'b>(
         &'b mut self,
         crop_marker: &'a str,
@@ -525,6 +1475,7 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.attributes_to_highlight = Some(attributes_to_highlight);
         self
     }
+    /// Replace the `<em>` tag inserted before highlighted terms.
     pub fn with_highlight_pre_tag<'b>(
         &'b mut self,
         highlight_pre_tag: &'a str,
@@ -532,6 +1483,7 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.highlight_pre_tag = Some(highlight_pre_tag);
         self
     }
+    /// Replace the `</em>` tag inserted after highlighted terms.
     pub fn with_highlight_post_tag<'b>(
         &'b mut self,
         highlight_post_tag: &'a str,
@@ -539,6 +1491,7 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.highlight_post_tag = Some(highlight_post_tag);
         self
     }
+    /// Return the position of the matched terms in [`SearchResult::matches_position`], to highlight them client-side.
     pub fn with_show_matches_position<'b>(
         &'b mut self,
         show_matches_position: bool,
@@ -565,7 +1518,7 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
 
     pub fn with_matching_strategy<'b>(
         &'b mut self,
-        matching_strategy: MatchingStrategies,
+        matching_strategy: MatchingStrategy,
     ) -> &'b mut SearchQuery<'a, Http> {
         self.matching_strategy = Some(matching_strategy);
         self
@@ -574,6 +1527,9 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.index_uid = Some(&self.index.uid);
         self
     }
+    /// Deduplicate the results on an attribute for this query only, overriding the `distinctAttribute` setting of the index.
+    ///
+    /// The attribute must be in the filterable attributes of the index.
     pub fn with_distinct<'b>(&'b mut self, distinct: &'a str) -> &'b mut SearchQuery<'a, Http> {
         self.distinct = Some(distinct);
         self
@@ -585,19 +1541,186 @@ impl<'a, Http: HttpClient> SearchQuery<'a, Http> {
         self.ranking_score_threshold = Some(ranking_score_threshold);
         self
     }
+    /// Force the languages used to tokenize the query, as [ISO-639](https://www.iso.org/iso-639-language-code) codes.
+    ///
+    /// This is useful when Meilisearch can't reliably detect the language of the query, for example with short CJK queries.
     pub fn with_locales<'b>(&'b mut self, locales: &'a [&'a str]) -> &'b mut SearchQuery<'a, Http> {
         self.locales = Some(locales);
         self
     }
+    /// Include the stored embeddings of each document in [`SearchResult::vectors`].
+    pub fn with_retrieve_vectors<'b>(
+        &'b mut self,
+        retrieve_vectors: bool,
+    ) -> &'b mut SearchQuery<'a, Http> {
+        self.retrieve_vectors = Some(retrieve_vectors);
+        self
+    }
     pub fn build(&mut self) -> SearchQuery<'a, Http> {
         self.clone()
     }
+    /// Drop `offset` and `limit` when the finite pagination mode (`page`/`hits_per_page`) is used, so both modes are never sent together.
+    pub(crate) fn with_consistent_pagination(&self) -> Cow<'_, SearchQuery<'a, Http>> {
+        let finite_pagination = self.page.is_some() || self.hits_per_page.is_some();
+        if finite_pagination && (self.offset.is_some() || self.limit.is_some()) {
+            let mut query = self.clone();
+            query.offset = None;
+            query.limit = None;
+            Cow::Owned(query)
+        } else {
+            Cow::Borrowed(self)
+        }
+    }
     /// Execute the query and fetch the results.
     pub async fn execute<T: 'static + DeserializeOwned + Send + Sync>(
         &'a self,
     ) -> Result<SearchResults<T>, Error> {
         self.index.execute_query::<T>(self).await
     }
+    /// Execute the query with a `GET` request, so CDNs and proxies can cache its results.
+    ///
+    /// See [`Index::execute_get_query`].
+    pub async fn execute_get<T: 'static + DeserializeOwned + Send + Sync>(
+        &'a self,
+    ) -> Result<SearchResults<T>, Error> {
+        self.index.execute_get_query::<T>(self).await
+    }
+    /// Execute the query and stream the matching documents, fetching them [`SEARCH_STREAM_PAGE_SIZE`] at a time.
+    ///
+    /// The `offset` and `limit` of the query delimit the streamed documents, and `page`/`hits_per_page` are ignored.
+    /// The stream ends once `limit` documents have been yielded or Meilisearch runs out of hits, which can happen before `estimatedTotalHits` is reached since the number of reachable hits is bounded by the [`max_total_hits`](crate::settings::PaginationSetting::max_total_hits) pagination setting of the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// # use futures::TryStreamExt;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: usize,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movies = client.index("execute_stream");
+    /// # let documents: Vec<Movie> = (0..250).map(|id| Movie { id }).collect();
+    /// # movies.add_documents(&documents, Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let movies: Vec<Movie> = movies
+    ///     .search()
+    ///     .with_limit(250)
+    ///     .execute_stream::<Movie>()
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(movies.len(), 250);
+    /// # client.index("execute_stream").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub fn execute_stream<T: 'static + DeserializeOwned + Send + Sync>(
+        &self,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a {
+        let mut query = self.clone();
+        query.page = None;
+        query.hits_per_page = None;
+        let offset = query.offset.unwrap_or(0);
+        let remaining = query.limit;
+
+        stream::try_unfold(
+            (query, offset, remaining, false),
+            |(mut query, offset, remaining, done)| async move {
+                if done || remaining == Some(0) {
+                    return Ok(None);
+                }
+
+                let page_size = remaining.map_or(SEARCH_STREAM_PAGE_SIZE, |remaining| {
+                    remaining.min(SEARCH_STREAM_PAGE_SIZE)
+                });
+                query.offset = Some(offset);
+                query.limit = Some(page_size);
+
+                let results = query.index.execute_query::<T>(&query).await?;
+                // The sort only needs to be validated once for the whole stream.
+                query.validate_sort = false;
+
+                let fetched = results.hits.len();
+                let offset = offset + fetched;
+                let remaining = remaining.map(|remaining| remaining - fetched);
+                let done = fetched < page_size
+                    || results
+                        .estimated_total_hits
+                        .is_some_and(|total| offset >= total);
+
+                let hits = results
+                    .hits
+                    .into_iter()
+                    .map(|hit| Ok::<T, Error>(hit.result));
+                Ok::<_, Error>(Some((stream::iter(hits), (query, offset, remaining, done))))
+            },
+        )
+        .try_flatten()
+    }
+}
+
+/// Encode a [`SearchQuery`] as the URL parameters of a `GET /indexes/{uid}/search` request.
+///
+/// Lists are comma-separated, except array filters which Meilisearch expects as JSON.
+pub(crate) fn search_query_to_url_params<Http: HttpClient>(
+    query: &SearchQuery<Http>,
+) -> Result<BTreeMap<String, String>, Error> {
+    let fields = match serde_json::to_value(query)? {
+        Value::Object(fields) => fields,
+        _ => Map::new(),
+    };
+
+    let mut params = BTreeMap::new();
+    for (name, value) in fields {
+        let param = match (name.as_str(), value) {
+            (_, Value::Null) | ("indexUid", _) => continue,
+            ("filter", Value::Array(filter)) => Value::Array(filter).to_string(),
+            (_, Value::String(value)) => value,
+            (_, Value::Array(values)) => values
+                .into_iter()
+                .map(|value| match value {
+                    Value::String(value) => value,
+                    value => value.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+            (_, value) => value.to_string(),
+        };
+        params.insert(name, param);
+    }
+    Ok(params)
+}
+
+/// Return the attributes of the `sort` parameter that are not covered by the `sortable` attributes of the index.
+pub(crate) fn unsortable_attributes<S: AsRef<str>>(sort: &[&str], sortable: &[S]) -> Vec<String> {
+    sort.iter()
+        // geo sorting relies on the `_geo` field and has its own syntax
+        .filter(|rule| !rule.starts_with("_geoPoint("))
+        .map(|rule| {
+            rule.rsplit_once(':')
+                .map_or(*rule, |(attribute, _)| attribute)
+        })
+        .filter(|attribute| {
+            !sortable.iter().any(|sortable| {
+                let sortable = sortable.as_ref();
+                sortable == "*"
+                    || sortable == *attribute
+                    || attribute
+                        .strip_prefix(sortable)
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+        })
+        .map(str::to_string)
+        .collect()
 }
 
 // TODO: Make it works with the serde derive macro
@@ -608,6 +1731,19 @@ pub struct MultiSearchQuery<'a, 'b, Http: HttpClient = DefaultHttpClient> {
     // #[serde(skip_serializing)]
     client: &'a Client<Http>,
     pub queries: Vec<SearchQuery<'b, Http>>,
+    pub federation: Option<FederationOptions>,
+}
+
+/// Options of a [federated](MultiSearchQuery::with_federation) search.
+#[derive(Debug, Serialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FederationOptions {
+    /// Number of merged hits to skip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    /// Maximum number of merged hits to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
 }
 
 impl<Http: HttpClient> Serialize for MultiSearchQuery<'_, '_, Http> {
@@ -615,8 +1751,11 @@ impl<Http: HttpClient> Serialize for MultiSearchQuery<'_, '_, Http> {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("MultiSearchQuery", 1)?;
+        let mut strukt = serializer.serialize_struct("MultiSearchQuery", 2)?;
         strukt.serialize_field("queries", &self.queries)?;
+        if let Some(federation) = &self.federation {
+            strukt.serialize_field("federation", federation)?;
+        }
         strukt.end()
     }
 }
@@ -628,12 +1767,14 @@ impl<'a, 'b, Http: HttpClient> MultiSearchQuery<'a, 'b, Http> {
         MultiSearchQuery {
             client,
             queries: Vec::new(),
+            federation: None,
         }
     }
     pub fn with_search_query(
         &mut self,
-        mut search_query: SearchQuery<'b, Http>,
+        search_query: SearchQuery<'b, Http>,
     ) -> &mut MultiSearchQuery<'a, 'b, Http> {
+        let mut search_query = search_query.with_consistent_pagination().into_owned();
         search_query.with_index_uid();
         self.queries.push(search_query);
         self
@@ -645,54 +1786,277 @@ impl<'a, 'b, Http: HttpClient> MultiSearchQuery<'a, 'b, Http> {
     ) -> Result<MultiSearchResponse<T>, Error> {
         self.client.execute_multi_search_query::<T>(self).await
     }
+
+    /// Merge the hits of all the queries into a single ranked list.
+    ///
+    /// A federated multi-search must be run with [`MultiSearchQuery::execute_federated`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: usize,
+    ///     title: String,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movies = client.index("with_federation_movies");
+    /// let series = client.index("with_federation_series");
+    /// # movies.add_documents(&[Movie { id: 0, title: String::from("Batman Begins") }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # series.add_documents(&[Movie { id: 0, title: String::from("Batman: The Animated Series") }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let results = client
+    ///     .multi_search()
+    ///     .with_search_query(movies.search().with_query("batman").build())
+    ///     .with_search_query(series.search().with_query("batman").build())
+    ///     .with_federation(FederationOptions {
+    ///         limit: Some(10),
+    ///         ..Default::default()
+    ///     })
+    ///     .execute_federated::<Movie>()
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(results.hits.len(), 2);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # series.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub fn with_federation(
+        &mut self,
+        federation: FederationOptions,
+    ) -> &mut MultiSearchQuery<'a, 'b, Http> {
+        self.federation = Some(federation);
+        self
+    }
+
+    /// Execute the [federated](MultiSearchQuery::with_federation) query and fetch the merged results.
+    pub async fn execute_federated<T: 'static + DeserializeOwned + Send + Sync>(
+        &'a self,
+    ) -> Result<FederatedSearchResults<T>, Error> {
+        self.client
+            .execute_federated_multi_search_query::<T>(self)
+            .await
+    }
 }
 #[derive(Debug, Clone, Deserialize)]
 pub struct MultiSearchResponse<T> {
     pub results: Vec<SearchResults<T>>,
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        client::*,
-        key::{Action, KeyBuilder},
-        search::*,
-    };
-    use big_s::S;
-    use meilisearch_test_macro::meilisearch_test;
-    use serde::{Deserialize, Serialize};
-    use serde_json::{json, Map, Value};
-
-    #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    struct Nested {
-        child: String,
-    }
-
-    #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    struct Document {
-        id: usize,
-        value: String,
-        kind: String,
-        number: i32,
-        nested: Nested,
-    }
-
-    impl PartialEq<Map<String, Value>> for Document {
-        #[allow(clippy::cmp_owned)]
-        fn eq(&self, rhs: &Map<String, Value>) -> bool {
-            self.id.to_string() == rhs["id"]
-                && self.value == rhs["value"]
-                && self.kind == rhs["kind"]
-        }
-    }
+/// The merged results of a [federated](MultiSearchQuery::with_federation) multi-search.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FederatedSearchResults<T> {
+    /// Results of the query, ranked across all the queried indexes.
+    pub hits: Vec<SearchResult<T>>,
+    /// Number of documents skipped.
+    pub offset: Option<usize>,
+    /// Number of results returned.
+    pub limit: Option<usize>,
+    /// Estimated total number of matches.
+    pub estimated_total_hits: Option<usize>,
+    /// Processing time of the query.
+    pub processing_time_ms: usize,
+}
 
-    async fn setup_test_index(client: &Client, index: &Index) -> Result<(), Error> {
-        let t0 = index.add_documents(&[
-            Document { id: 0, kind: "text".into(), number: 0, value: S("Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum."), nested: Nested { child: S("first") } },
-            Document { id: 1, kind: "text".into(), number: 10, value: S("dolor sit amet, consectetur adipiscing elit"), nested: Nested { child: S("second") } },
-            Document { id: 2, kind: "title".into(), number: 20, value: S("The Social Network"), nested: Nested { child: S("third") } },
-            Document { id: 3, kind: "title".into(), number: 30, value: S("Harry Potter and the Sorcerer's Stone"), nested: Nested { child: S("fourth") } },
-            Document { id: 4, kind: "title".into(), number: 40, value: S("Harry Potter and the Chamber of Secrets"), nested: Nested { child: S("fift") } },
+/// A struct representing a facet-search query.
+///
+/// Facet search returns the values of a facet matching `facet_query`, along with the number of documents containing them.
+/// This is typically used to autocomplete filters.
+///
+/// # Example
+///
+/// ```
+/// # use serde::{Serialize, Deserialize};
+/// # use meilisearch_sdk::{client::*, indexes::*, search::*, settings::Settings};
+/// #
+/// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+/// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+/// #
+/// #[derive(Serialize, Deserialize, Debug)]
+/// struct Movie {
+///     id: usize,
+///     genre: String,
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+/// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+/// let index = client.index("facet_search_query");
+/// # index.set_filterable_attributes(["genre"]).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+/// # index.add_documents(&[Movie { id: 0, genre: String::from("action") }, Movie { id: 1, genre: String::from("adventure") }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+///
+/// let res = FacetSearchQuery::new(&index, "genre")
+///     .with_facet_query("adv")
+///     .execute()
+///     .await
+///     .unwrap();
+///
+/// assert_eq!(res.facet_hits[0].value, "adventure");
+/// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+/// # });
+/// ```
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetSearchQuery<'a, Http: HttpClient = DefaultHttpClient> {
+    #[serde(skip_serializing)]
+    index: &'a Index<Http>,
+    /// The facet whose values are searched. It must be a filterable attribute of the index.
+    pub facet_name: &'a str,
+    /// The text that will be searched for among the facet values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_query: Option<&'a str>,
+    /// The text that will be searched for among the documents, only the facet values of the matching documents are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "q")]
+    pub search_query: Option<&'a str>,
+    /// Filter applied to documents, only the facet values of the matching documents are returned.
+    ///
+    /// Read the [dedicated guide](https://www.meilisearch.com/docs/learn/advanced/filtering) to learn the syntax.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter<'a>>,
+    /// Defines the strategy on how to handle search queries containing multiple words.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_strategy: Option<MatchingStrategy>,
+    /// Restrict the search of `search_query` to the specified attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes_to_search_on: Option<&'a [&'a str]>,
+}
+
+#[allow(missing_docs)]
+impl<'a, Http: HttpClient> FacetSearchQuery<'a, Http> {
+    #[must_use]
+    pub fn new(index: &'a Index<Http>, facet_name: &'a str) -> FacetSearchQuery<'a, Http> {
+        FacetSearchQuery {
+            index,
+            facet_name,
+            facet_query: None,
+            search_query: None,
+            filter: None,
+            matching_strategy: None,
+            attributes_to_search_on: None,
+        }
+    }
+    pub fn with_facet_query<'b>(
+        &'b mut self,
+        facet_query: &'a str,
+    ) -> &'b mut FacetSearchQuery<'a, Http> {
+        self.facet_query = Some(facet_query);
+        self
+    }
+    pub fn with_search_query<'b>(
+        &'b mut self,
+        search_query: &'a str,
+    ) -> &'b mut FacetSearchQuery<'a, Http> {
+        self.search_query = Some(search_query);
+        self
+    }
+    pub fn with_filter<'b>(&'b mut self, filter: &'a str) -> &'b mut FacetSearchQuery<'a, Http> {
+        self.filter = Some(Filter::new(Either::Left(filter)));
+        self
+    }
+    pub fn with_array_filter<'b>(
+        &'b mut self,
+        filter: Vec<&'a str>,
+    ) -> &'b mut FacetSearchQuery<'a, Http> {
+        self.filter = Some(Filter::new(Either::Right(filter)));
+        self
+    }
+    pub fn with_matching_strategy<'b>(
+        &'b mut self,
+        matching_strategy: MatchingStrategy,
+    ) -> &'b mut FacetSearchQuery<'a, Http> {
+        self.matching_strategy = Some(matching_strategy);
+        self
+    }
+    pub fn with_attributes_to_search_on<'b>(
+        &'b mut self,
+        attributes_to_search_on: &'a [&'a str],
+    ) -> &'b mut FacetSearchQuery<'a, Http> {
+        self.attributes_to_search_on = Some(attributes_to_search_on);
+        self
+    }
+    pub fn build(&mut self) -> FacetSearchQuery<'a, Http> {
+        self.clone()
+    }
+    /// Execute the query and fetch the matching facet values.
+    pub async fn execute(&'a self) -> Result<FacetSearchResponse, Error> {
+        self.index.facet_search(self).await
+    }
+}
+
+/// A facet value matching a [`FacetSearchQuery`].
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct FacetHit {
+    /// The facet value.
+    pub value: String,
+    /// The number of documents containing this facet value.
+    pub count: usize,
+}
+
+/// The response of a [`FacetSearchQuery`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetSearchResponse {
+    /// The facet values matching the query.
+    pub facet_hits: Vec<FacetHit>,
+    /// The facet query that was searched.
+    pub facet_query: Option<String>,
+    /// Processing time of the query.
+    pub processing_time_ms: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::*,
+        key::{Action, KeyBuilder},
+        search::*,
+        tenant_tokens::{IndexSearchRules, SearchRules},
+    };
+    use big_s::S;
+    use meilisearch_test_macro::meilisearch_test;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{json, Map, Value};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Nested {
+        child: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Document {
+        id: usize,
+        value: String,
+        kind: String,
+        number: i32,
+        nested: Nested,
+    }
+
+    impl PartialEq<Map<String, Value>> for Document {
+        #[allow(clippy::cmp_owned)]
+        fn eq(&self, rhs: &Map<String, Value>) -> bool {
+            self.id.to_string() == rhs["id"]
+                && self.value == rhs["value"]
+                && self.kind == rhs["kind"]
+        }
+    }
+
+    async fn setup_test_index(client: &Client, index: &Index) -> Result<(), Error> {
+        let t0 = index.add_documents(&[
+            Document { id: 0, kind: "text".into(), number: 0, value: S("Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum."), nested: Nested { child: S("first") } },
+            Document { id: 1, kind: "text".into(), number: 10, value: S("dolor sit amet, consectetur adipiscing elit"), nested: Nested { child: S("second") } },
+            Document { id: 2, kind: "title".into(), number: 20, value: S("The Social Network"), nested: Nested { child: S("third") } },
+            Document { id: 3, kind: "title".into(), number: 30, value: S("Harry Potter and the Sorcerer's Stone"), nested: Nested { child: S("fourth") } },
+            Document { id: 4, kind: "title".into(), number: 40, value: S("Harry Potter and the Chamber of Secrets"), nested: Nested { child: S("fift") } },
             Document { id: 5, kind: "title".into(), number: 50, value: S("Harry Potter and the Prisoner of Azkaban"), nested: Nested { child: S("sixth") } },
             Document { id: 6, kind: "title".into(), number: 60, value: S("Harry Potter and the Goblet of Fire"), nested: Nested { child: S("seventh") } },
             Document { id: 7, kind: "title".into(), number: 70, value: S("Harry Potter and the Order of the Phoenix"), nested: Nested { child: S("eighth") } },
@@ -733,93 +2097,646 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_multi_search_different_types(client: Client, index: Index) -> Result<(), Error> {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Other {
+            id: usize,
+            label: String,
+        }
+
+        setup_test_index(&client, &index).await?;
+        let other_index = client.index(format!("{}_other", index.uid));
+        other_index
+            .add_documents(
+                &[Other {
+                    id: 0,
+                    label: S("Sorcerer"),
+                }],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let response = client
+            .multi_search()
+            .with_search_query(index.search().with_query("Sorcerer's Stone").build())
+            .with_search_query(other_index.search().with_query("Sorcerer").build())
+            .execute::<Value>()
+            .await?;
+
+        let mut results = response.results.into_iter();
+        let documents = results.next().unwrap().into_typed::<Document>()?;
+        let others = results.next().unwrap().into_typed::<Other>()?;
+
+        assert_eq!(documents.hits[0].result.id, 3);
+        assert_eq!(
+            others.hits[0].result,
+            Other {
+                id: 0,
+                label: S("Sorcerer")
+            }
+        );
+
+        other_index
+            .delete()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_federated_multi_search(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+        let other_index = client.index(format!("{}_federated", index.uid));
+        other_index
+            .add_documents(
+                &[
+                    Document {
+                        id: 0,
+                        kind: S("title"),
+                        number: 0,
+                        value: S("Harry Potter and the Cursed Child"),
+                        nested: Nested { child: S("first") },
+                    },
+                    Document {
+                        id: 1,
+                        kind: S("title"),
+                        number: 10,
+                        value: S("Fantastic Beasts and Where to Find Them"),
+                        nested: Nested { child: S("second") },
+                    },
+                ],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let mut multi_search = client.multi_search();
+        multi_search
+            .with_search_query(index.search().with_query("harry potter").build())
+            .with_search_query(other_index.search().with_query("harry potter").build())
+            .with_federation(FederationOptions {
+                limit: Some(20),
+                ..Default::default()
+            });
+        assert_eq!(
+            serde_json::to_value(&multi_search).unwrap()["federation"],
+            json!({ "limit": 20 })
+        );
+
+        let results = multi_search.execute_federated::<Document>().await?;
+        let federation: Vec<&FederationHitInfo> = results
+            .hits
+            .iter()
+            .map(|hit| hit.federation.as_ref().unwrap())
+            .collect();
+
+        assert!(federation.iter().any(|info| info.index_uid == index.uid));
+        assert!(federation
+            .iter()
+            .any(|info| info.index_uid == other_index.uid && info.queries_position == 1));
+        assert!(federation
+            .windows(2)
+            .all(|pair| pair[0].weighted_ranking_score >= pair[1].weighted_ranking_score));
+
+        other_index
+            .delete()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_facet_search(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let res = FacetSearchQuery::new(&index, "kind")
+            .with_facet_query("ti")
+            .execute()
+            .await?;
+        assert_eq!(res.facet_query.as_deref(), Some("ti"));
+        assert_eq!(
+            res.facet_hits,
+            vec![FacetHit {
+                value: S("title"),
+                count: 8
+            }]
+        );
+
+        let query = FacetSearchQuery::new(&index, "kind")
+            .with_search_query("dolor")
+            .with_filter("number < 20")
+            .build();
+        let res = index.facet_search(&query).await?;
+        assert_eq!(
+            res.facet_hits,
+            vec![FacetHit {
+                value: S("text"),
+                count: 2
+            }]
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_facet_search_serialization(index: Index) -> Result<(), Error> {
+        let query = FacetSearchQuery::new(&index, "genre")
+            .with_facet_query("adv")
+            .with_search_query("space")
+            .with_array_filter(vec!["year > 2000"])
+            .with_matching_strategy(MatchingStrategy::Last)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({
+                "facetName": "genre",
+                "facetQuery": "adv",
+                "q": "space",
+                "filter": ["year > 2000"],
+                "matchingStrategy": "last",
+            })
+        );
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_query_builder(_client: Client, index: Index) -> Result<(), Error> {
         let mut query = SearchQuery::new(&index);
         query.with_query("space").with_offset(42).with_limit(21);
 
-        let res = query.execute::<Document>().await.unwrap();
+        let res = query.execute::<Document>().await.unwrap();
+
+        assert_eq!(res.query, S("space"));
+        assert_eq!(res.limit, Some(21));
+        assert_eq!(res.offset, Some(42));
+        assert_eq!(res.estimated_total_hits, Some(0));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_numbered_pagination(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = SearchQuery::new(&index);
+        query.with_query("").with_page(2).with_hits_per_page(2);
+
+        let res = query.execute::<Document>().await.unwrap();
+
+        assert_eq!(res.page, Some(2));
+        assert_eq!(res.hits_per_page, Some(2));
+        assert_eq!(res.total_hits, Some(10));
+        assert_eq!(res.total_pages, Some(5));
+        assert_eq!(res.estimated_total_hits, None);
+        assert_eq!(res.hit_count(), Some(10));
+
+        let res = SearchQuery::new(&index)
+            .with_limit(2)
+            .execute::<Document>()
+            .await?;
+        assert_eq!(res.total_hits, None);
+        assert_eq!(res.hit_count(), Some(10));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_string(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let results: SearchResults<Document> = index.search().with_query("dolor").execute().await?;
+        assert_eq!(results.hits.len(), 2);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_empty_query_is_a_placeholder_search(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = SearchQuery::new(&index);
+        query.with_query("");
+        assert_eq!(serde_json::to_value(&query).unwrap()["q"], json!(""));
+        let placeholder: SearchResults<Document> = query.execute().await?;
+
+        let query = SearchQuery::new(&index);
+        assert_eq!(serde_json::to_value(&query).unwrap().get("q"), None);
+        let without_query: SearchResults<Document> = query.execute().await?;
+
+        assert_eq!(placeholder.hits.len(), 10);
+        assert_eq!(placeholder.hit_count(), without_query.hit_count());
+        assert_eq!(
+            placeholder
+                .hits
+                .iter()
+                .map(|hit| hit.result.id)
+                .collect::<Vec<_>>(),
+            without_query
+                .hits
+                .iter()
+                .map(|hit| hit.result.id)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_search_query_to_url_params(index: Index) {
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("harry")
+            .with_limit(3)
+            .with_array_filter(vec!["kind = title", "number > 20"])
+            .with_attributes_to_retrieve(Selectors::Some(&["id", "value"]))
+            .with_show_ranking_score(true);
+
+        let params = search_query_to_url_params(&query).unwrap();
+        let expected: BTreeMap<String, String> = vec![
+            ("q", "harry"),
+            ("limit", "3"),
+            ("filter", r#"["kind = title","number > 20"]"#),
+            ("attributesToRetrieve", "id,value"),
+            ("showRankingScore", "true"),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+        assert_eq!(params, expected);
+
+        let params = search_query_to_url_params(
+            &SearchQuery::new(&index)
+                .with_filter("kind = text")
+                .with_attributes_to_retrieve(Selectors::All)
+                .build(),
+        )
+        .unwrap();
+        assert_eq!(params["filter"], "kind = text");
+        assert_eq!(params["attributesToRetrieve"], "*");
+    }
+
+    #[meilisearch_test]
+    async fn test_execute_get_query(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("harry")
+            .with_array_filter(vec!["kind = title", "number > 50"])
+            .with_attributes_to_retrieve(Selectors::Some(&["id", "value"]));
+
+        let get_results: SearchResults<Value> = query.execute_get().await?;
+        let post_results: SearchResults<Value> = query.execute().await?;
+
+        assert_eq!(get_results.hits.len(), 4);
+        assert_eq!(
+            get_results
+                .hits
+                .iter()
+                .map(|hit| &hit.result)
+                .collect::<Vec<_>>(),
+            post_results
+                .hits
+                .iter()
+                .map(|hit| &hit.result)
+                .collect::<Vec<_>>()
+        );
+        assert!(get_results.hits[0].result.get("kind").is_none());
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_string_on_nested_field(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let results: SearchResults<Document> =
+            index.search().with_query("second").execute().await?;
+
+        assert_eq!(
+            &Document {
+                id: 1,
+                value: S("dolor sit amet, consectetur adipiscing elit"),
+                kind: S("text"),
+                number: 10,
+                nested: Nested { child: S("second") }
+            },
+            &results.hits[0].result
+        );
+
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_limit(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let results: SearchResults<Document> = index.search().with_limit(5).execute().await?;
+        assert_eq!(results.hits.len(), 5);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_execute_stream(client: Client, index: Index) -> Result<(), Error> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Numbered {
+            id: usize,
+        }
+
+        let documents: Vec<Numbered> = (0..5 * SEARCH_STREAM_PAGE_SIZE)
+            .map(|id| Numbered { id })
+            .collect();
+        index
+            .add_documents(&documents, Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let ids: Vec<usize> = index
+            .search()
+            .with_limit(5 * SEARCH_STREAM_PAGE_SIZE)
+            .execute_stream::<Numbered>()
+            .map_ok(|document| document.id)
+            .try_collect()
+            .await?;
+        assert_eq!(ids.len(), 5 * SEARCH_STREAM_PAGE_SIZE);
+        let unique: std::collections::HashSet<usize> = ids.iter().copied().collect();
+        assert_eq!(unique.len(), ids.len());
+
+        let ids: Vec<usize> = index
+            .search()
+            .with_offset(490)
+            .execute_stream::<Numbered>()
+            .map_ok(|document| document.id)
+            .try_collect()
+            .await?;
+        assert_eq!(ids.len(), 10);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_page(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let results: SearchResults<Document> = index.search().with_page(2).execute().await?;
+        assert_eq!(results.page, Some(2));
+        assert_eq!(results.hits_per_page, Some(20));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_hits_per_page(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let results: SearchResults<Document> =
+            index.search().with_hits_per_page(2).execute().await?;
+        assert_eq!(results.page, Some(1));
+        assert_eq!(results.hits_per_page, Some(2));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_page_overrides_offset_and_limit(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = index.search();
+        query
+            .with_offset(1)
+            .with_limit(3)
+            .with_page(2)
+            .with_hits_per_page(4);
+        let body = serde_json::to_value(&*query.with_consistent_pagination()).unwrap();
+        assert_eq!(body.get("offset"), None);
+        assert_eq!(body.get("limit"), None);
+        assert_eq!(body["page"], json!(2));
+        assert_eq!(body["hitsPerPage"], json!(4));
+
+        let results: SearchResults<Document> = query.execute().await?;
+        assert_eq!(results.page, Some(2));
+        assert_eq!(results.hits_per_page, Some(4));
+        assert_eq!(results.total_hits, Some(10));
+        assert_eq!(results.total_pages, Some(3));
+        assert_eq!(results.hits.len(), 4);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_attributes_to_crop_serialization(index: Index) {
+        let mut query = SearchQuery::new(&index);
+        query.with_attributes_to_crop(Selectors::Some(&[
+            ("overview", Some(20)),
+            ("tagline", Some(5)),
+            ("title", None),
+        ]));
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["attributesToCrop"],
+            json!(["overview:20", "tagline:5", "title"])
+        );
+
+        query.with_attributes_to_crop(Selectors::All);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["attributesToCrop"],
+            json!(["*"])
+        );
+    }
+
+    #[test]
+    fn test_search_results_summary() {
+        let results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [{ "id": 1 }],
+            "page": 1,
+            "hitsPerPage": 1,
+            "totalHits": 10,
+            "totalPages": 10,
+            "processingTimeMs": 0,
+            "query": "",
+        }))
+        .unwrap();
+        assert_eq!(results.summary(), "1 hit (total 10) in 0ms for query ''");
+
+        let results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [],
+            "processingTimeMs": 12,
+            "query": "nothing",
+        }))
+        .unwrap();
+        assert_eq!(results.to_string(), "0 hits in 12ms for query 'nothing'");
+    }
+
+    #[test]
+    fn test_filter_expression() {
+        assert_eq!(
+            FilterExpression::eq("genre", "Sci-Fi")
+                .and(FilterExpression::gte("rating", 4.5))
+                .to_string(),
+            r#"genre = "Sci-Fi" AND rating >= 4.5"#
+        );
+        assert_eq!(
+            FilterExpression::eq("title", r#"The "Best" \ Worst"#).to_string(),
+            r#"title = "The \"Best\" \\ Worst""#
+        );
+        assert_eq!(
+            FilterExpression::eq("genre", "horror")
+                .or(FilterExpression::eq("genre", "comedy"))
+                .and(FilterExpression::to("year", 2000, 2010))
+                .and(!FilterExpression::exists("director"))
+                .to_string(),
+            r#"(genre = "horror" OR genre = "comedy") AND year 2000 TO 2010 AND NOT director EXISTS"#
+        );
+        assert_eq!(
+            (!FilterExpression::is_null("a").or(FilterExpression::is_empty("b"))).to_string(),
+            "NOT (a IS NULL OR b IS EMPTY)"
+        );
+        assert_eq!(
+            FilterExpression::in_list("id", [1, 2, 3])
+                .and(FilterExpression::ne("released", true))
+                .to_string(),
+            "id IN [1, 2, 3] AND released != true"
+        );
+        // integers are not rounded to the nearest float
+        assert_eq!(
+            FilterExpression::eq("id", u64::MAX)
+                .and(FilterExpression::lt("balance", i64::MIN + 1))
+                .to_string(),
+            "id = 18446744073709551615 AND balance < -9223372036854775807"
+        );
+        assert_eq!(
+            FilterExpression::eq("order", "asc").to_string(),
+            r#""order" = "asc""#
+        );
+        assert_eq!(
+            FilterExpression::geo_radius(45.472735, 9.184019, 2000.0).to_string(),
+            "_geoRadius(45.472735, 9.184019, 2000)"
+        );
+        assert_eq!(
+            FilterExpression::geo_bounding_box((45.494181, 9.214024), (45.449484, 9.179175))
+                .to_string(),
+            "_geoBoundingBox([45.494181, 9.214024], [45.449484, 9.179175])"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "filter values must be finite numbers")]
+    fn test_filter_expression_rejects_nan() {
+        let _ = FilterExpression::gt("rating", f64::NAN);
+    }
 
-        assert_eq!(res.query, S("space"));
-        assert_eq!(res.limit, Some(21));
-        assert_eq!(res.offset, Some(42));
-        assert_eq!(res.estimated_total_hits, Some(0));
-        Ok(())
+    #[test]
+    fn test_geo_point() {
+        let point: GeoPoint = serde_json::from_value(json!({ "lat": 48.8, "lng": 2.3 })).unwrap();
+        assert_eq!(point, GeoPoint::new(48.8, 2.3));
+        assert_eq!(point.sort_rule(false), "_geoPoint(48.8, 2.3):desc");
     }
 
     #[meilisearch_test]
-    async fn test_query_numbered_pagination(client: Client, index: Index) -> Result<(), Error> {
-        setup_test_index(&client, &index).await?;
-
-        let mut query = SearchQuery::new(&index);
-        query.with_query("").with_page(2).with_hits_per_page(2);
+    async fn test_query_geo(client: Client, index: Index) -> Result<(), Error> {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Place {
+            id: usize,
+            #[serde(rename = "_geo")]
+            geo: GeoPoint,
+        }
 
-        let res = query.execute::<Document>().await.unwrap();
+        let task = index
+            .add_documents(
+                &[
+                    Place {
+                        id: 0,
+                        geo: GeoPoint::new(45.4777599, 9.1967508),
+                    },
+                    Place {
+                        id: 1,
+                        geo: GeoPoint::new(48.8826517, 2.3352748),
+                    },
+                ],
+                Some("id"),
+            )
+            .await?;
+        index
+            .set_filterable_attributes(["_geo"])
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        index
+            .set_sortable_attributes(["_geo"])
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        task.wait_for_completion(&client, None, None).await?;
 
-        assert_eq!(res.page, Some(2));
-        assert_eq!(res.hits_per_page, Some(2));
-        assert_eq!(res.total_hits, Some(10));
-        assert_eq!(res.total_pages, Some(5));
-        Ok(())
-    }
+        let results: SearchResults<Place> = index
+            .search()
+            .with_geo_radius(45.472735, 9.184019, 2000.0)
+            .execute()
+            .await?;
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].result.id, 0);
 
-    #[meilisearch_test]
-    async fn test_query_string(client: Client, index: Index) -> Result<(), Error> {
-        setup_test_index(&client, &index).await?;
+        let results: SearchResults<Place> = index
+            .search()
+            .with_geo_bounding_box((49.0, 2.5), (48.0, 2.0))
+            .execute()
+            .await?;
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].result.id, 1);
 
-        let results: SearchResults<Document> = index.search().with_query("dolor").execute().await?;
-        assert_eq!(results.hits.len(), 2);
+        let sort_rule = GeoPoint::new(48.8, 2.3).sort_rule(true);
+        let sort = [sort_rule.as_str()];
+        let results: SearchResults<Place> = index.search().with_sort(&sort).execute().await?;
+        assert_eq!(results.hits[0].result.id, 1);
+        assert!(results.hits.iter().all(|hit| hit.geo_distance.is_some()));
         Ok(())
     }
 
     #[meilisearch_test]
-    async fn test_query_string_on_nested_field(client: Client, index: Index) -> Result<(), Error> {
+    async fn test_query_filter_expr(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
 
-        let results: SearchResults<Document> =
-            index.search().with_query("second").execute().await?;
-
+        let filter = FilterExpression::eq("kind", "title").and(
+            FilterExpression::lt("number", 40)
+                .or(FilterExpression::eq("value", "The Social Network")),
+        );
+        let mut query = index.search();
+        query.with_filter_expr(&filter);
         assert_eq!(
-            &Document {
-                id: 1,
-                value: S("dolor sit amet, consectetur adipiscing elit"),
-                kind: S("text"),
-                number: 10,
-                nested: Nested { child: S("second") }
-            },
-            &results.hits[0].result
+            serde_json::to_value(&query).unwrap()["filter"],
+            json!(r#"kind = "title" AND (number < 40 OR value = "The Social Network")"#)
         );
 
+        let results: SearchResults<Document> = query.execute().await?;
+        let mut ids: Vec<usize> = results.hits.iter().map(|hit| hit.result.id).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![2, 3]);
         Ok(())
     }
 
     #[meilisearch_test]
-    async fn test_query_limit(client: Client, index: Index) -> Result<(), Error> {
-        setup_test_index(&client, &index).await?;
-
-        let results: SearchResults<Document> = index.search().with_limit(5).execute().await?;
-        assert_eq!(results.hits.len(), 5);
-        Ok(())
-    }
-
-    #[meilisearch_test]
-    async fn test_query_page(client: Client, index: Index) -> Result<(), Error> {
+    async fn test_query_clear(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
 
-        let results: SearchResults<Document> = index.search().with_page(2).execute().await?;
-        assert_eq!(results.page, Some(2));
-        assert_eq!(results.hits_per_page, Some(20));
-        Ok(())
-    }
+        let mut query = index.search();
+        query
+            .with_filter("kind = text")
+            .with_sort(&["title:asc"])
+            .with_facets(Selectors::All);
+        let results: SearchResults<Document> = query.execute().await?;
+        assert_eq!(results.hits.len(), 2);
 
-    #[meilisearch_test]
-    async fn test_query_hits_per_page(client: Client, index: Index) -> Result<(), Error> {
-        setup_test_index(&client, &index).await?;
+        query.clear_filter().clear_sort().clear_facets();
+        let body = serde_json::to_value(&query).unwrap();
+        assert_eq!(body.get("filter"), None);
+        assert_eq!(body.get("sort"), None);
+        assert_eq!(body.get("facets"), None);
 
-        let results: SearchResults<Document> =
-            index.search().with_hits_per_page(2).execute().await?;
-        assert_eq!(results.page, Some(1));
-        assert_eq!(results.hits_per_page, Some(2));
+        let results: SearchResults<Document> = query.execute().await?;
+        assert_eq!(results.hits.len(), 10);
+        assert!(results.facet_distribution.is_none());
         Ok(())
     }
 
@@ -869,6 +2786,56 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_query_without_facets(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let results: SearchResults<Document> = index.search().execute().await?;
+        assert!(results.facet_distribution.is_none());
+        assert!(results.facet_stats.is_none());
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_facet_stats(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let results: SearchResults<Document> = index
+            .search()
+            .with_facets(Selectors::Some(&["number", "kind"]))
+            .with_filter("number > 10")
+            .execute()
+            .await?;
+
+        let facet_stats = results.facet_stats.unwrap();
+        let number = facet_stats.get("number").unwrap();
+        assert_eq!(number.min, 20.0);
+        assert_eq!(number.max, 90.0);
+        assert!(!facet_stats.contains_key("kind"));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_facet_distribution_is_capped(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+        index
+            .set_faceting(&crate::settings::FacetingSettings {
+                max_values_per_facet: 1,
+            })
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let mut query = SearchQuery::new(&index);
+        query.with_facets(Selectors::Some(&["kind"]));
+        let results: SearchResults<Document> = index.execute_query(&query).await?;
+        assert_eq!(results.facet_distribution.unwrap()["kind"].len(), 1);
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_query_facet_distribution(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -914,6 +2881,37 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_query_attributes_to_search_on(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        // "second" only appears in the `nested.child` attribute of the second document
+        let results: SearchResults<Document> =
+            index.search().with_query("second").execute().await?;
+        assert_eq!(results.hits.len(), 1);
+
+        let results: SearchResults<Document> = index
+            .search()
+            .with_query("second")
+            .with_attributes_to_search_on(&["value"])
+            .execute()
+            .await?;
+        assert!(results.hits.is_empty());
+
+        let query = index
+            .search()
+            .with_attributes_to_search_on(&["value", "kind"])
+            .build();
+        assert_eq!(
+            serde_json::to_value(&query)?["attributesToSearchOn"],
+            json!(["value", "kind"])
+        );
+        assert!(serde_json::to_value(index.search().build())?
+            .get("attributesToSearchOn")
+            .is_none());
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_query_attributes_to_retrieve(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -943,6 +2941,199 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_query_sort_validation(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let error = index
+            .search()
+            .with_sort(&["title:desc", "kind:asc"])
+            .with_sort_validation(true)
+            .execute::<Document>()
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, Error::UnsortableAttributes(attributes) if attributes == vec![S("kind")])
+        );
+
+        let results = index
+            .search()
+            .with_query("harry potter")
+            .with_sort(&["title:desc"])
+            .with_sort_validation(true)
+            .execute::<Document>()
+            .await?;
+        assert_eq!(results.hits.len(), 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_escape_filter_attribute() {
+        assert_eq!(escape_filter_attribute("kind"), "kind");
+        assert_eq!(escape_filter_attribute("nested.child"), "nested.child");
+        assert_eq!(escape_filter_attribute("to"), r#""to""#);
+        assert_eq!(escape_filter_attribute("TO"), r#""TO""#);
+        assert_eq!(escape_filter_attribute("order"), r#""order""#);
+        assert_eq!(escape_filter_attribute("_geoRadius"), r#""_geoRadius""#);
+        assert_eq!(escape_filter_attribute("a b"), r#""a b""#);
+        assert_eq!(escape_filter_attribute(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(escape_filter_attribute(""), r#""""#);
+    }
+
+    #[meilisearch_test]
+    async fn test_query_filter_escaped_attribute(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        let task = index
+            .add_documents(
+                &[json!({ "id": 1, "to": 5 }), json!({ "id": 2, "to": 10 })],
+                Some("id"),
+            )
+            .await?;
+        index
+            .set_filterable_attributes(["to"])
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        task.wait_for_completion(&client, None, None).await?;
+
+        let filter = format!("{} > 6", escape_filter_attribute("to"));
+        let results: SearchResults<Value> = index.search().with_filter(&filter).execute().await?;
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].result["id"], json!(2));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_sort_validation_with_sortable_attributes() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let settings = s
+            .mock("GET", "/indexes/movies/settings/sortable-attributes")
+            .expect(0)
+            .create_async()
+            .await;
+        let search = s
+            .mock("POST", "/indexes/movies/search")
+            .with_status(200)
+            .with_body(r#"{"hits": [], "query": "", "processingTimeMs": 0, "limit": 20, "offset": 0, "estimatedTotalHits": 0}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(s.url(), None::<String>).unwrap();
+        let index = client.index("movies");
+
+        let error = index
+            .search()
+            .with_sort(&["title:desc", "kind:asc"])
+            .with_sortable_attributes(&["title"])
+            .execute::<Value>()
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, Error::UnsortableAttributes(attributes) if attributes == vec![S("kind")])
+        );
+
+        index
+            .search()
+            .with_sort(&["title:desc"])
+            .with_sortable_attributes(&["title"])
+            .execute::<Value>()
+            .await?;
+
+        settings.assert_async().await;
+        search.assert_async().await;
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsortable_attributes() {
+        let sortable = [S("title"), S("nested")];
+
+        assert!(unsortable_attributes(&["title:asc", "nested.child:desc"], &sortable).is_empty());
+        assert!(unsortable_attributes(&["_geoPoint(48.8, 2.3):asc"], &sortable).is_empty());
+        assert_eq!(
+            unsortable_attributes(&["title:asc", "kind:desc", "nestedness:asc"], &sortable),
+            vec![S("kind"), S("nestedness")]
+        );
+        assert!(unsortable_attributes(&["kind:desc"], &[S("*")]).is_empty());
+    }
+
+    #[meilisearch_test]
+    async fn test_query_crop_and_highlight_same_attribute(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("consectetur")
+            .with_filter("id = 0")
+            .with_attributes_to_crop(Selectors::Some(&[("value", Some(6))]))
+            .with_attributes_to_highlight(Selectors::Some(&["value"]))
+            .with_crop_marker("[…]")
+            .with_highlight_pre_tag("<mark>")
+            .with_highlight_post_tag("</mark>");
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["attributesToCrop"],
+            json!(["value:6"])
+        );
+
+        let results: SearchResults<Document> = index.execute_query(&query).await?;
+        let formatted_value = results.hits[0].formatted_result.as_ref().unwrap()["value"]
+            .as_str()
+            .unwrap();
+
+        assert!(formatted_value.contains("<mark>consectetur</mark>"));
+        assert!(formatted_value.starts_with("[…]"));
+        assert!(formatted_value.ends_with("[…]"));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_retrieve_vectors(client: Client, index: Index) -> Result<(), Error> {
+        let mut embedders = std::collections::HashMap::new();
+        embedders.insert(S("default"), crate::settings::Embedder::user_provided(2));
+        index
+            .set_embedders(&embedders)
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        let task = index
+            .add_documents(
+                &[
+                    json!({ "id": 0, "_vectors": { "default": [0.1, 0.2] } }),
+                    json!({ "id": 1, "_vectors": { "default": [0.3, 0.4] } }),
+                ],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+
+        let mut query = SearchQuery::new(&index);
+        query.with_retrieve_vectors(true);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["retrieveVectors"],
+            json!(true)
+        );
+
+        let results: SearchResults<Value> = index.execute_query(&query).await?;
+        assert_eq!(results.hits.len(), 2);
+        for hit in &results.hits {
+            assert!(hit.vectors.is_some());
+            assert!(hit.vectors.as_ref().unwrap()["default"]["embeddings"].is_array());
+        }
+
+        let results: SearchResults<Value> = index.search().execute().await?;
+        assert_eq!(results.hits.len(), 2);
+        assert!(results.hits.iter().all(|hit| hit.vectors.is_none()));
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_query_attributes_to_crop(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -1068,6 +3259,34 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_query_markdown_highlight_tags(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = SearchQuery::new(&index);
+        query.with_query("Social");
+        query.with_attributes_to_highlight(Selectors::Some(&["value"]));
+        let body = serde_json::to_value(&query).unwrap();
+        assert_eq!(body.get("highlightPreTag"), None);
+        assert_eq!(body.get("highlightPostTag"), None);
+        assert_eq!(body.get("cropMarker"), None);
+
+        query.with_highlight_pre_tag("**");
+        query.with_highlight_post_tag("**");
+        query.with_crop_marker("...");
+        let body = serde_json::to_value(&query).unwrap();
+        assert_eq!(body["highlightPreTag"], json!("**"));
+        assert_eq!(body["highlightPostTag"], json!("**"));
+        assert_eq!(body["cropMarker"], json!("..."));
+
+        let results: SearchResults<Document> = index.execute_query(&query).await?;
+        assert_eq!(
+            results.hits[0].formatted_result.as_ref().unwrap()["value"],
+            json!("The **Social** Network")
+        );
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_query_attributes_to_highlight(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -1125,9 +3344,76 @@ mod tests {
                 length: 5
             }]
         );
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["showMatchesPosition"],
+            json!(true)
+        );
+
+        let mut query = SearchQuery::new(&index);
+        query.with_query("dolor text");
+        let results: SearchResults<Document> = index.execute_query(&query).await?;
+        assert!(results.hits[0].matches_position.is_none());
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_query_all_hit_metadata(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("dolor text")
+            .with_show_matches_position(true)
+            .with_show_ranking_score(true)
+            .with_show_ranking_score_details(true)
+            .with_attributes_to_highlight(Selectors::All)
+            .with_retrieve_vectors(true);
+        let results: SearchResults<Document> = index.execute_query(&query).await?;
+        let hit = &results.hits[0];
+
+        assert_eq!(hit.result.id, 1);
+        assert!(hit.formatted_result.as_ref().unwrap()["value"]
+            .as_str()
+            .unwrap()
+            .contains("<em>dolor</em>"));
+        assert!(hit.matches_position.as_ref().unwrap().contains_key("value"));
+        assert!(hit.ranking_score.is_some());
+        assert!(hit.typed_ranking_score_details()?.unwrap().words.is_some());
         Ok(())
     }
 
+    #[test]
+    fn test_deserialize_hit_with_all_metadata() {
+        let hit: SearchResult<Value> = serde_json::from_value(json!({
+            "id": 1,
+            "value": "dolor sit amet",
+            "_formatted": { "id": "1", "value": "<em>dolor</em> sit amet" },
+            "_matchesPosition": { "value": [{ "start": 0, "length": 5 }] },
+            "_rankingScore": 0.9,
+            "_rankingScoreDetails": { "words": { "order": 0, "matchingWords": 1, "maxMatchingWords": 1, "score": 1.0 } },
+            "_vectors": { "default": { "embeddings": [[0.5]], "regenerate": false } },
+            "_federation": { "indexUid": "movies", "queriesPosition": 0, "weightedRankingScore": 0.9 },
+        }))
+        .unwrap();
+
+        assert_eq!(hit.result, json!({ "id": 1, "value": "dolor sit amet" }));
+        assert_eq!(
+            hit.formatted_result.unwrap()["value"],
+            json!("<em>dolor</em> sit amet")
+        );
+        assert_eq!(
+            hit.matches_position.unwrap()["value"],
+            vec![MatchRange {
+                start: 0,
+                length: 5
+            }]
+        );
+        assert_eq!(hit.ranking_score, Some(0.9));
+        assert!(hit.ranking_score_details.unwrap().contains_key("words"));
+        assert!(hit.vectors.unwrap().contains_key("default"));
+        assert_eq!(hit.federation.unwrap().index_uid, "movies");
+    }
+
     #[meilisearch_test]
     async fn test_query_show_ranking_score(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -1152,9 +3438,32 @@ mod tests {
         query.with_show_ranking_score_details(true);
         let results: SearchResults<Document> = index.execute_query(&query).await.unwrap();
         assert!(results.hits[0].ranking_score_details.is_some());
+
+        let details = results.hits[0].typed_ranking_score_details()?.unwrap();
+        assert!(details.words.is_some());
+        assert!(details.typo.is_some());
         Ok(())
     }
 
+    #[test]
+    fn test_typed_ranking_score_details() {
+        let result: SearchResult<Value> = serde_json::from_value(json!({
+            "id": 1,
+            "_rankingScoreDetails": {
+                "words": { "order": 0, "matchingWords": 2, "maxMatchingWords": 2, "score": 1.0 },
+                "typo": { "order": 1, "typoCount": 0, "maxTypoCount": 2, "score": 1.0 },
+                "title:asc": { "order": 2, "value": "Hello" }
+            }
+        }))
+        .unwrap();
+
+        let details = result.typed_ranking_score_details().unwrap().unwrap();
+        assert_eq!(details.words.unwrap().matching_words, 2);
+        assert_eq!(details.typo.unwrap().max_typo_count, 2);
+        assert!(details.proximity.is_none());
+        assert_eq!(details.other["title:asc"]["value"], json!("Hello"));
+    }
+
     #[meilisearch_test]
     async fn test_query_show_ranking_score_threshold(
         client: Client,
@@ -1177,8 +3486,12 @@ mod tests {
         let mut query = SearchQuery::new(&index);
         query.with_query("Harry Styles");
         query.with_locales(&["eng"]);
+        assert_eq!(serde_json::to_value(&query)?["locales"], json!(["eng"]));
         let results: SearchResults<Document> = index.execute_query(&query).await.unwrap();
         assert_eq!(results.hits.len(), 7);
+
+        let query = SearchQuery::new(&index).with_query("Harry Styles").build();
+        assert!(serde_json::to_value(&query)?.get("locales").is_none());
         Ok(())
     }
 
@@ -1200,7 +3513,7 @@ mod tests {
 
         let results = SearchQuery::new(&index)
             .with_query("Harry Styles")
-            .with_matching_strategy(MatchingStrategies::ALL)
+            .with_matching_strategy(MatchingStrategy::All)
             .execute::<Document>()
             .await
             .unwrap();
@@ -1215,7 +3528,7 @@ mod tests {
 
         let results = SearchQuery::new(&index)
             .with_query("Harry Styles")
-            .with_matching_strategy(MatchingStrategies::LAST)
+            .with_matching_strategy(MatchingStrategy::Last)
             .execute::<Document>()
             .await
             .unwrap();
@@ -1230,7 +3543,7 @@ mod tests {
 
         let results = SearchQuery::new(&index)
             .with_query("Harry Styles")
-            .with_matching_strategy(MatchingStrategies::FREQUENCY)
+            .with_matching_strategy(MatchingStrategy::Frequency)
             .execute::<Document>()
             .await
             .unwrap();
@@ -1239,6 +3552,41 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_matching_strategy_serialization(client: Client) -> Result<(), Error> {
+        let index = client.index("test_matching_strategy_serialization");
+
+        let query = SearchQuery::new(&index).with_query("Harry").build();
+        let value = serde_json::to_value(&query)?;
+        assert!(value.get("matchingStrategy").is_none());
+
+        let query = SearchQuery::new(&index)
+            .with_matching_strategy(MatchingStrategy::Frequency)
+            .build();
+        let value = serde_json::to_value(&query)?;
+        assert_eq!(value["matchingStrategy"], json!("frequency"));
+        Ok(())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_matching_strategies() {
+        let strategies: [MatchingStrategies; 3] = [
+            MatchingStrategies::ALL,
+            MatchingStrategies::LAST,
+            MatchingStrategies::FREQUENCY,
+        ];
+
+        assert_eq!(
+            strategies,
+            [
+                MatchingStrategy::All,
+                MatchingStrategy::Last,
+                MatchingStrategy::Frequency
+            ]
+        );
+    }
+
     #[meilisearch_test]
     async fn test_distinct(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -1250,6 +3598,11 @@ mod tests {
             .unwrap();
 
         assert_eq!(results.hits.len(), 2);
+
+        let query = SearchQuery::new(&index).with_distinct("kind").build();
+        assert_eq!(serde_json::to_value(&query)?["distinct"], json!("kind"));
+        let query = SearchQuery::new(&index).build();
+        assert!(serde_json::to_value(&query)?.get("distinct").is_none());
         Ok(())
     }
 
@@ -1270,11 +3623,15 @@ mod tests {
         let allowed_client = Client::new(meilisearch_url, Some(key.key)).unwrap();
 
         let search_rules = vec![
-            json!({ "*": {}}),
-            json!({ "*": Value::Null }),
-            json!(["*"]),
-            json!({ "*": { "filter": "kind = text" } }),
-            json!([index.uid.to_string()]),
+            SearchRules::Filters(
+                vec![(S("*"), Some(IndexSearchRules::default()))]
+                    .into_iter()
+                    .collect(),
+            ),
+            SearchRules::Filters(vec![(S("*"), None)].into_iter().collect()),
+            SearchRules::all(),
+            SearchRules::all().with_filter("*", "kind = text"),
+            SearchRules::indexes([index.uid.as_str()]),
         ];
 
         for rules in search_rules {
@@ -1,5 +1,5 @@
 use crate::{
-    errors::Error,
+    errors::{Error, SettingsValidationError},
     indexes::Index,
     request::{HttpClient, Method},
     task_info::TaskInfo,
@@ -36,6 +36,336 @@ pub struct FacetingSettings {
     pub max_values_per_facet: usize,
 }
 
+/// An [embedder](https://www.meilisearch.com/docs/reference/api/settings#embedders) generating the vectors of the documents, tagged by its `source`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "source")]
+pub enum Embedder {
+    /// Compute embeddings locally with a model from HuggingFace.
+    #[serde(rename = "huggingFace")]
+    HuggingFace(HuggingFaceEmbedderSettings),
+    /// Compute embeddings with the OpenAI API.
+    #[serde(rename = "openAi")]
+    OpenAI(OpenAIEmbedderSettings),
+    /// Compute embeddings with an Ollama server.
+    #[serde(rename = "ollama")]
+    Ollama(OllamaEmbedderSettings),
+    /// Compute embeddings with any REST API.
+    #[serde(rename = "rest")]
+    REST(GenericRestEmbedderSettings),
+    /// Provide the embeddings yourself in the `_vectors` field of the documents.
+    #[serde(rename = "userProvided")]
+    UserProvided(UserProvidedEmbedderSettings),
+    /// Use one embedder to index the documents and another one to embed the search queries.
+    ///
+    /// Both embedders must generate the same embeddings, for example the same model running locally for the queries and behind an API for the documents.
+    /// Requires Meilisearch 1.13 or newer, with the [`compositeEmbedders`](crate::features::ExperimentalFeatures::set_composite_embedders) experimental feature.
+    #[serde(rename = "composite", rename_all = "camelCase")]
+    Composite {
+        search_embedder: Box<Embedder>,
+        indexing_embedder: Box<Embedder>,
+    },
+    /// An embedder whose `source` this version of the SDK doesn't know, kept as raw JSON.
+    #[serde(untagged)]
+    Unknown {
+        source: String,
+        #[serde(flatten)]
+        settings: serde_json::Map<String, serde_json::Value>,
+    },
+}
+
+impl Embedder {
+    /// An [`Embedder::OpenAI`] using the given api key and the default model of Meilisearch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::{Embedder, Settings};
+    /// # use std::collections::HashMap;
+    /// let embedder = Embedder::open_ai("sk-xxx")
+    ///     .with_model("text-embedding-3-small")
+    ///     .with_dimensions(512)
+    ///     .with_document_template("A movie titled {{doc.title}}");
+    ///
+    /// assert_eq!(embedder.source(), "openAi");
+    ///
+    /// let mut embedders = HashMap::new();
+    /// embedders.insert("default", embedder);
+    /// let settings = Settings::new().with_embedders(embedders);
+    /// ```
+    #[must_use]
+    pub fn open_ai(api_key: impl Into<String>) -> Embedder {
+        Embedder::OpenAI(OpenAIEmbedderSettings {
+            api_key: Some(api_key.into()),
+            ..OpenAIEmbedderSettings::default()
+        })
+    }
+
+    /// An [`Embedder::HuggingFace`] running the given model locally.
+    #[must_use]
+    pub fn hugging_face(model: impl Into<String>) -> Embedder {
+        Embedder::HuggingFace(HuggingFaceEmbedderSettings {
+            model: Some(model.into()),
+            ..HuggingFaceEmbedderSettings::default()
+        })
+    }
+
+    /// An [`Embedder::Ollama`] using the given model of the default Ollama server.
+    #[must_use]
+    pub fn ollama(model: impl Into<String>) -> Embedder {
+        Embedder::Ollama(OllamaEmbedderSettings {
+            model: Some(model.into()),
+            ..OllamaEmbedderSettings::default()
+        })
+    }
+
+    /// An [`Embedder::REST`] sending its requests to `url`.
+    ///
+    /// The `request` and `response` templates of the [`GenericRestEmbedderSettings`] still have to be set.
+    #[must_use]
+    pub fn rest(url: impl Into<String>) -> Embedder {
+        Embedder::REST(GenericRestEmbedderSettings {
+            url: url.into(),
+            ..GenericRestEmbedderSettings::default()
+        })
+    }
+
+    /// An [`Embedder::UserProvided`] expecting embeddings of the given dimensions.
+    #[must_use]
+    pub fn user_provided(dimensions: usize) -> Embedder {
+        Embedder::UserProvided(UserProvidedEmbedderSettings {
+            dimensions,
+            ..UserProvidedEmbedderSettings::default()
+        })
+    }
+
+    /// An [`Embedder::Composite`] embedding the search queries and the documents with different embedders.
+    #[must_use]
+    pub fn composite(search_embedder: Embedder, indexing_embedder: Embedder) -> Embedder {
+        Embedder::Composite {
+            search_embedder: Box::new(search_embedder),
+            indexing_embedder: Box::new(indexing_embedder),
+        }
+    }
+
+    /// The `source` of the embedder, as sent to Meilisearch.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        match self {
+            Embedder::HuggingFace(_) => "huggingFace",
+            Embedder::OpenAI(_) => "openAi",
+            Embedder::Ollama(_) => "ollama",
+            Embedder::REST(_) => "rest",
+            Embedder::UserProvided(_) => "userProvided",
+            Embedder::Composite { .. } => "composite",
+            Embedder::Unknown { source, .. } => source,
+        }
+    }
+
+    /// Set the model of the embedder.
+    ///
+    /// Ignored by the REST, user-provided, composite and unknown embedders.
+    #[must_use]
+    pub fn with_model(mut self, model: impl Into<String>) -> Embedder {
+        match &mut self {
+            Embedder::HuggingFace(settings) => settings.model = Some(model.into()),
+            Embedder::OpenAI(settings) => settings.model = Some(model.into()),
+            Embedder::Ollama(settings) => settings.model = Some(model.into()),
+            Embedder::REST(_)
+            | Embedder::UserProvided(_)
+            | Embedder::Composite { .. }
+            | Embedder::Unknown { .. } => {}
+        }
+        self
+    }
+
+    /// Set the dimensions of the embeddings.
+    ///
+    /// Ignored by the HuggingFace and composite embedders, whose dimensions are inferred, and by the unknown embedders.
+    #[must_use]
+    pub fn with_dimensions(mut self, dimensions: usize) -> Embedder {
+        match &mut self {
+            Embedder::OpenAI(settings) => settings.dimensions = Some(dimensions),
+            Embedder::Ollama(settings) => settings.dimensions = Some(dimensions),
+            Embedder::REST(settings) => settings.dimensions = Some(dimensions),
+            Embedder::UserProvided(settings) => settings.dimensions = dimensions,
+            Embedder::HuggingFace(_) | Embedder::Composite { .. } | Embedder::Unknown { .. } => {}
+        }
+        self
+    }
+
+    /// Set the Liquid template used to render the documents sent to the embedder.
+    ///
+    /// Ignored by the user-provided and composite embedders, which don't render documents, and by the unknown embedders.
+    #[must_use]
+    pub fn with_document_template(mut self, document_template: impl Into<String>) -> Embedder {
+        match &mut self {
+            Embedder::HuggingFace(settings) => {
+                settings.document_template = Some(document_template.into());
+            }
+            Embedder::OpenAI(settings) => {
+                settings.document_template = Some(document_template.into())
+            }
+            Embedder::Ollama(settings) => {
+                settings.document_template = Some(document_template.into())
+            }
+            Embedder::REST(settings) => settings.document_template = Some(document_template.into()),
+            Embedder::UserProvided(_) | Embedder::Composite { .. } | Embedder::Unknown { .. } => {}
+        }
+        self
+    }
+
+    /// Store the embeddings with one bit per dimension, trading relevancy for disk space.
+    ///
+    /// Ignored by the composite embedders, set it on the embedders they are made of, and by the unknown embedders.
+    #[must_use]
+    pub fn with_binary_quantized(mut self, binary_quantized: bool) -> Embedder {
+        match &mut self {
+            Embedder::HuggingFace(settings) => settings.binary_quantized = Some(binary_quantized),
+            Embedder::OpenAI(settings) => settings.binary_quantized = Some(binary_quantized),
+            Embedder::Ollama(settings) => settings.binary_quantized = Some(binary_quantized),
+            Embedder::REST(settings) => settings.binary_quantized = Some(binary_quantized),
+            Embedder::UserProvided(settings) => settings.binary_quantized = Some(binary_quantized),
+            Embedder::Composite { .. } | Embedder::Unknown { .. } => {}
+        }
+        self
+    }
+}
+
+/// Affine transformation applied to the semantic scores of an [Embedder] to normalize them.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct DistributionShift {
+    pub mean: f64,
+    pub sigma: f64,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HuggingFaceEmbedderSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// Liquid template used to render the document sent to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, longer texts are truncated. **Default = 400 bytes**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<DistributionShift>,
+    /// Store the embeddings with one bit per dimension, trading relevancy for disk space.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenAIEmbedderSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+    /// Liquid template used to render the document sent to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, longer texts are truncated. **Default = 400 bytes**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<DistributionShift>,
+    /// Store the embeddings with one bit per dimension, trading relevancy for disk space.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OllamaEmbedderSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+    /// Liquid template used to render the document sent to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, longer texts are truncated. **Default = 400 bytes**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<DistributionShift>,
+    /// Store the embeddings with one bit per dimension, trading relevancy for disk space.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GenericRestEmbedderSettings {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+    /// Liquid template used to render the document sent to the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// Maximum size of the rendered document template, longer texts are truncated. **Default = 400 bytes**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template_max_bytes: Option<usize>,
+    /// Template of the request body, where `{{text}}` is replaced by the rendered documents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<serde_json::Value>,
+    /// Template of the response body, where `{{embedding}}` marks the location of the embeddings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<DistributionShift>,
+    /// Store the embeddings with one bit per dimension, trading relevancy for disk space.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProvidedEmbedderSettings {
+    pub dimensions: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<DistributionShift>,
+    /// Store the embeddings with one bit per dimension, trading relevancy for disk space.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_quantized: Option<bool>,
+}
+
+/// When Meilisearch computes the data needed to match query words by their prefix.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum PrefixSearch {
+    /// Compute prefixes at indexing time. This is the default.
+    IndexingTime,
+    /// Disable prefix search, which makes indexing faster.
+    Disabled,
+}
+
+/// How precisely Meilisearch computes the proximity between query words.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProximityPrecision {
+    /// Compute the exact distance between words. This is the default.
+    ByWord,
+    /// Only check whether words are in the same attribute, which makes indexing faster.
+    ByAttribute,
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LocalizedAttributes {
@@ -68,7 +398,7 @@ pub struct LocalizedAttributes {
 ///     ..Settings::new()
 /// };
 /// ```
-#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
     /// List of associated words treated similarly.
@@ -109,7 +439,7 @@ pub struct Settings {
     pub dictionary: Option<Vec<String>>,
     /// Proximity precision settings.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub proximity_precision: Option<String>,
+    pub proximity_precision: Option<ProximityPrecision>,
     /// SearchCutoffMs settings.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search_cutoff_ms: Option<u64>,
@@ -122,6 +452,15 @@ pub struct Settings {
     /// LocalizedAttributes settings.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub localized_attributes: Option<Vec<LocalizedAttributes>>,
+    /// Prefix search settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix_search: Option<PrefixSearch>,
+    /// Whether the facet search is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_search: Option<bool>,
+    /// Embedders generating the vectors of the documents, by name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedders: Option<HashMap<String, Embedder>>,
 }
 
 #[allow(missing_docs)]
@@ -301,9 +640,10 @@ impl Settings {
         }
     }
 
-    pub fn with_proximity_precision(self, proximity_precision: impl AsRef<str>) -> Settings {
+    #[must_use]
+    pub fn with_proximity_precision(self, proximity_precision: ProximityPrecision) -> Settings {
         Settings {
-            proximity_precision: Some(proximity_precision.as_ref().to_string()),
+            proximity_precision: Some(proximity_precision),
             ..self
         }
     }
@@ -357,6 +697,255 @@ impl Settings {
             ..self
         }
     }
+
+    #[must_use]
+    pub fn with_prefix_search(self, prefix_search: PrefixSearch) -> Settings {
+        Settings {
+            prefix_search: Some(prefix_search),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_facet_search(self, facet_search: bool) -> Settings {
+        Settings {
+            facet_search: Some(facet_search),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_embedders<S>(self, embedders: HashMap<S, Embedder>) -> Settings
+    where
+        S: AsRef<str>,
+    {
+        Settings {
+            embedders: Some(
+                embedders
+                    .into_iter()
+                    .map(|(name, embedder)| (name.as_ref().to_string(), embedder))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    /// Check the ranking rules are either built-in rules or custom `attribute:asc` / `attribute:desc` rules.
+    ///
+    /// Unlike the checks done by [`Index::set_settings`], this rejects any rule the SDK doesn't know about,
+    /// so a typo fails before reaching Meilisearch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{errors::SettingsValidationError, settings::Settings};
+    /// let settings = Settings::new().with_ranking_rules(["words", "typo", "release_date:desc"]);
+    /// assert!(settings.validate_ranking_rules().is_ok());
+    ///
+    /// let settings = Settings::new().with_ranking_rules(["words", "exactnes"]);
+    /// assert!(matches!(
+    ///     settings.validate_ranking_rules(),
+    ///     Err(SettingsValidationError::InvalidRankingRule { rule, .. }) if rule == "exactnes"
+    /// ));
+    /// ```
+    pub fn validate_ranking_rules(&self) -> Result<(), SettingsValidationError> {
+        let ranking_rules = match &self.ranking_rules {
+            Some(ranking_rules) => ranking_rules,
+            None => return Ok(()),
+        };
+        validate_ranking_rules(ranking_rules)?;
+
+        for rule in ranking_rules {
+            if BUILT_IN_RANKING_RULES.contains(&rule.as_str()) {
+                continue;
+            }
+            let is_custom_rule = match rule.rsplit_once(':') {
+                Some((attribute, order)) => {
+                    !attribute.is_empty() && (order == "asc" || order == "desc")
+                }
+                None => false,
+            };
+            if !is_custom_rule {
+                return Err(SettingsValidationError::InvalidRankingRule {
+                    rule: rule.clone(),
+                    reason: format!(
+                        "expected one of {} or a custom `attribute:asc` / `attribute:desc` rule",
+                        BUILT_IN_RANKING_RULES.join(", ")
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The settings of `desired` that are set and differ from `self`, to only send what changed with [`Index::set_settings`].
+    ///
+    /// The embedders are compared one by one, since Meilisearch updates each embedder separately.
+    /// Settings returned by Meilisearch have every field filled, so compare them with settings that were fetched too, or
+    /// set every field of `desired` to its value on the server to avoid sending identical values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let current = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_search_cutoff(150);
+    /// let desired = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_search_cutoff(300);
+    ///
+    /// let delta = current.diff(&desired);
+    /// assert_eq!(delta, Settings::new().with_search_cutoff(300));
+    ///
+    /// // Nothing to send when the settings are already up to date
+    /// assert_eq!(desired.diff(&desired), Settings::new());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, desired: &Settings) -> Settings {
+        let embedders = desired.embedders.as_ref().and_then(|desired_embedders| {
+            let changed: HashMap<String, Embedder> = desired_embedders
+                .iter()
+                .filter(|(name, embedder)| {
+                    self.embedders
+                        .as_ref()
+                        .and_then(|embedders| embedders.get(*name))
+                        != Some(*embedder)
+                })
+                .map(|(name, embedder)| (name.clone(), embedder.clone()))
+                .collect();
+            if changed.is_empty() {
+                None
+            } else {
+                Some(changed)
+            }
+        });
+
+        Settings {
+            synonyms: changed(&self.synonyms, &desired.synonyms),
+            stop_words: changed(&self.stop_words, &desired.stop_words),
+            ranking_rules: changed(&self.ranking_rules, &desired.ranking_rules),
+            filterable_attributes: changed(
+                &self.filterable_attributes,
+                &desired.filterable_attributes,
+            ),
+            sortable_attributes: changed(&self.sortable_attributes, &desired.sortable_attributes),
+            distinct_attribute: changed(&self.distinct_attribute, &desired.distinct_attribute),
+            searchable_attributes: changed(
+                &self.searchable_attributes,
+                &desired.searchable_attributes,
+            ),
+            displayed_attributes: changed(
+                &self.displayed_attributes,
+                &desired.displayed_attributes,
+            ),
+            pagination: changed(&self.pagination, &desired.pagination),
+            faceting: changed(&self.faceting, &desired.faceting),
+            typo_tolerance: changed(&self.typo_tolerance, &desired.typo_tolerance),
+            dictionary: changed(&self.dictionary, &desired.dictionary),
+            proximity_precision: changed(&self.proximity_precision, &desired.proximity_precision),
+            search_cutoff_ms: changed(&self.search_cutoff_ms, &desired.search_cutoff_ms),
+            separator_tokens: changed(&self.separator_tokens, &desired.separator_tokens),
+            non_separator_tokens: changed(
+                &self.non_separator_tokens,
+                &desired.non_separator_tokens,
+            ),
+            localized_attributes: changed(
+                &self.localized_attributes,
+                &desired.localized_attributes,
+            ),
+            prefix_search: changed(&self.prefix_search, &desired.prefix_search),
+            facet_search: changed(&self.facet_search, &desired.facet_search),
+            embedders,
+        }
+    }
+
+    /// Overlay the settings set in `other` on top of `self`, like Meilisearch does when updating the settings.
+    ///
+    /// The embedders are merged one by one: the embedders of `self` that are not in `other` are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let base = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_search_cutoff(150);
+    /// let overrides = Settings::new().with_search_cutoff(300);
+    ///
+    /// let settings = base.merge(overrides);
+    /// assert_eq!(settings.stop_words, Some(vec!["a".to_string(), "the".to_string()]));
+    /// assert_eq!(settings.search_cutoff_ms, Some(300));
+    /// ```
+    #[must_use]
+    pub fn merge(self, other: Settings) -> Settings {
+        let embedders = match (self.embedders, other.embedders) {
+            (Some(mut embedders), Some(other_embedders)) => {
+                embedders.extend(other_embedders);
+                Some(embedders)
+            }
+            (embedders, other_embedders) => other_embedders.or(embedders),
+        };
+
+        Settings {
+            synonyms: other.synonyms.or(self.synonyms),
+            stop_words: other.stop_words.or(self.stop_words),
+            ranking_rules: other.ranking_rules.or(self.ranking_rules),
+            filterable_attributes: other.filterable_attributes.or(self.filterable_attributes),
+            sortable_attributes: other.sortable_attributes.or(self.sortable_attributes),
+            distinct_attribute: other.distinct_attribute.or(self.distinct_attribute),
+            searchable_attributes: other.searchable_attributes.or(self.searchable_attributes),
+            displayed_attributes: other.displayed_attributes.or(self.displayed_attributes),
+            pagination: other.pagination.or(self.pagination),
+            faceting: other.faceting.or(self.faceting),
+            typo_tolerance: other.typo_tolerance.or(self.typo_tolerance),
+            dictionary: other.dictionary.or(self.dictionary),
+            proximity_precision: other.proximity_precision.or(self.proximity_precision),
+            search_cutoff_ms: other.search_cutoff_ms.or(self.search_cutoff_ms),
+            separator_tokens: other.separator_tokens.or(self.separator_tokens),
+            non_separator_tokens: other.non_separator_tokens.or(self.non_separator_tokens),
+            localized_attributes: other.localized_attributes.or(self.localized_attributes),
+            prefix_search: other.prefix_search.or(self.prefix_search),
+            facet_search: other.facet_search.or(self.facet_search),
+            embedders,
+        }
+    }
+}
+
+/// The `desired` value, if it is set and differs from the `current` one.
+fn changed<T: PartialEq + Clone>(current: &Option<T>, desired: &Option<T>) -> Option<T> {
+    match desired {
+        Some(desired) if current.as_ref() != Some(desired) => Some(desired.clone()),
+        _ => None,
+    }
+}
+
+/// The ranking rules built into Meilisearch.
+const BUILT_IN_RANKING_RULES: &[&str] = &[
+    "words",
+    "typo",
+    "proximity",
+    "attribute",
+    "sort",
+    "exactness",
+];
+
+/// Reject the ranking rules Meilisearch is known to refuse, with a more helpful message than the server's.
+pub(crate) fn validate_ranking_rules(
+    ranking_rules: &[String],
+) -> Result<(), SettingsValidationError> {
+    if let Some(rule) = ranking_rules
+        .iter()
+        .find(|rule| rule.starts_with("_geoPoint("))
+    {
+        return Err(SettingsValidationError::InvalidRankingRule {
+            rule: rule.clone(),
+            reason: "geo sorting can't be used as a ranking rule. Keep the `sort` ranking rule and sort by `_geoPoint` at search time with `SearchQuery::with_sort` instead".to_string(),
+        });
+    }
+
+    Ok(())
 }
 
 impl<Http: HttpClient> Index<Http> {
@@ -781,10 +1370,10 @@ impl<Http: HttpClient> Index<Http> {
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_proximity_precision(&self) -> Result<String, Error> {
+    pub async fn get_proximity_precision(&self) -> Result<ProximityPrecision, Error> {
         self.client
             .http_client
-            .request::<(), (), String>(
+            .request::<(), (), ProximityPrecision>(
                 &format!(
                     "{}/indexes/{}/settings/proximity-precision",
                     self.client.host, self.uid
@@ -954,6 +1543,106 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Get [prefix search](https://www.meilisearch.com/docs/reference/api/settings#prefix-search) settings of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("get_prefix_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_prefix_search");
+    ///
+    /// let prefix_search = index.get_prefix_search().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_prefix_search(&self) -> Result<PrefixSearch, Error> {
+        self.client
+            .http_client
+            .request::<(), (), PrefixSearch>(
+                &format!(
+                    "{}/indexes/{}/settings/prefix-search",
+                    self.client.host, self.uid
+                ),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+    }
+
+    /// Get [facet search](https://www.meilisearch.com/docs/reference/api/settings#facet-search) settings of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("get_facet_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_facet_search");
+    ///
+    /// let facet_search = index.get_facet_search().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_facet_search(&self) -> Result<bool, Error> {
+        self.client
+            .http_client
+            .request::<(), (), bool>(
+                &format!(
+                    "{}/indexes/{}/settings/facet-search",
+                    self.client.host, self.uid
+                ),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+    }
+
+    /// Get [embedders](https://www.meilisearch.com/docs/reference/api/settings#embedders) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("get_embedders", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_embedders");
+    ///
+    /// let embedders = index.get_embedders().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_embedders(&self) -> Result<HashMap<String, Embedder>, Error> {
+        self.client
+            .http_client
+            .request::<(), (), Option<HashMap<String, Embedder>>>(
+                &format!(
+                    "{}/indexes/{}/settings/embedders",
+                    self.client.host, self.uid
+                ),
+                Method::Get { query: () },
+                200,
+            )
+            .await
+            .map(Option::unwrap_or_default)
+    }
+
     /// Update [settings](../settings/struct.Settings) of the [Index].
     ///
     /// Updates in the settings are partial. This means that any parameters corresponding to a `None` value will be left unchanged.
@@ -982,6 +1671,10 @@ impl<Http: HttpClient> Index<Http> {
     /// # });
     /// ```
     pub async fn set_settings(&self, settings: &Settings) -> Result<TaskInfo, Error> {
+        if let Some(ranking_rules) = &settings.ranking_rules {
+            validate_ranking_rules(ranking_rules)?;
+        }
+
         self.client
             .http_client
             .request::<(), &Settings, TaskInfo>(
@@ -1152,6 +1845,12 @@ impl<Http: HttpClient> Index<Http> {
         &self,
         ranking_rules: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Result<TaskInfo, Error> {
+        let ranking_rules: Vec<String> = ranking_rules
+            .into_iter()
+            .map(|v| v.as_ref().to_string())
+            .collect();
+        validate_ranking_rules(&ranking_rules)?;
+
         self.client
             .http_client
             .request::<(), Vec<String>, TaskInfo>(
@@ -1161,10 +1860,7 @@ impl<Http: HttpClient> Index<Http> {
                 ),
                 Method::Put {
                     query: (),
-                    body: ranking_rules
-                        .into_iter()
-                        .map(|v| v.as_ref().to_string())
-                        .collect(),
+                    body: ranking_rules,
                 },
                 202,
             )
@@ -1595,7 +2291,7 @@ impl<Http: HttpClient> Index<Http> {
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::{Settings, ProximityPrecision}};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
@@ -1605,17 +2301,17 @@ impl<Http: HttpClient> Index<Http> {
     /// # client.create_index("set_proximity_precision", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// let mut index = client.index("set_proximity_precision");
     ///
-    /// let task = index.set_proximity_precision("byWord".to_string()).await.unwrap();
+    /// let task = index.set_proximity_precision(ProximityPrecision::ByWord).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
     pub async fn set_proximity_precision(
         &self,
-        proximity_precision: String,
+        proximity_precision: ProximityPrecision,
     ) -> Result<TaskInfo, Error> {
         self.client
             .http_client
-            .request::<(), String, TaskInfo>(
+            .request::<(), ProximityPrecision, TaskInfo>(
                 &format!(
                     "{}/indexes/{}/settings/proximity-precision",
                     self.client.host, self.uid
@@ -1709,6 +2405,130 @@ impl<Http: HttpClient> Index<Http> {
             .await
     }
 
+    /// Update [prefix search](https://www.meilisearch.com/docs/reference/api/settings#prefix-search) settings of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::PrefixSearch};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("set_prefix_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("set_prefix_search");
+    ///
+    /// let task = index.set_prefix_search(PrefixSearch::Disabled).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_prefix_search(&self, prefix_search: PrefixSearch) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), PrefixSearch, TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/prefix-search",
+                    self.client.host, self.uid
+                ),
+                Method::Put {
+                    query: (),
+                    body: prefix_search,
+                },
+                202,
+            )
+            .await
+    }
+
+    /// Update [facet search](https://www.meilisearch.com/docs/reference/api/settings#facet-search) settings of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("set_facet_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("set_facet_search");
+    ///
+    /// let task = index.set_facet_search(false).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_facet_search(&self, facet_search: bool) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), bool, TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/facet-search",
+                    self.client.host, self.uid
+                ),
+                Method::Put {
+                    query: (),
+                    body: facet_search,
+                },
+                202,
+            )
+            .await
+    }
+
+    /// Add or update [embedders](https://www.meilisearch.com/docs/reference/api/settings#embedders) of the [Index].
+    ///
+    /// The update is partial: the embedders of the index that are not in `embedders` are left unchanged,
+    /// so a new embedder can be registered without resending the existing ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::*};
+    /// # use std::collections::HashMap;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("set_embedders", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("set_embedders");
+    ///
+    /// let mut embedders = HashMap::new();
+    /// embedders.insert(
+    ///     "manual".to_string(),
+    ///     Embedder::UserProvided(UserProvidedEmbedderSettings {
+    ///         dimensions: 3,
+    ///         ..UserProvidedEmbedderSettings::default()
+    ///     }),
+    /// );
+    ///
+    /// let task = index.set_embedders(&embedders).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_embedders(
+        &self,
+        embedders: &HashMap<String, Embedder>,
+    ) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), &HashMap<String, Embedder>, TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/embedders",
+                    self.client.host, self.uid
+                ),
+                Method::Patch {
+                    query: (),
+                    body: embedders,
+                },
+                202,
+            )
+            .await
+    }
+
     /// Reset [Settings] of the [Index].
     ///
     /// All settings will be reset to their [default value](https://www.meilisearch.com/docs/reference/api/settings#reset-settings).
@@ -2303,6 +3123,105 @@ impl<Http: HttpClient> Index<Http> {
             )
             .await
     }
+
+    /// Reset [prefix search](https://www.meilisearch.com/docs/reference/api/settings#prefix-search) settings of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("reset_prefix_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("reset_prefix_search");
+    ///
+    /// let task = index.reset_prefix_search().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_prefix_search(&self) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), (), TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/prefix-search",
+                    self.client.host, self.uid
+                ),
+                Method::Delete { query: () },
+                202,
+            )
+            .await
+    }
+
+    /// Reset [facet search](https://www.meilisearch.com/docs/reference/api/settings#facet-search) settings of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("reset_facet_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("reset_facet_search");
+    ///
+    /// let task = index.reset_facet_search().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_facet_search(&self) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), (), TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/facet-search",
+                    self.client.host, self.uid
+                ),
+                Method::Delete { query: () },
+                202,
+            )
+            .await
+    }
+
+    /// Reset [embedders](https://www.meilisearch.com/docs/reference/api/settings#embedders) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # client.create_index("reset_embedders", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("reset_embedders");
+    ///
+    /// let task = index.reset_embedders().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn reset_embedders(&self) -> Result<TaskInfo, Error> {
+        self.client
+            .http_client
+            .request::<(), (), TaskInfo>(
+                &format!(
+                    "{}/indexes/{}/settings/embedders",
+                    self.client.host, self.uid
+                ),
+                Method::Delete { query: () },
+                202,
+            )
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -2310,8 +3229,70 @@ mod tests {
     use super::*;
 
     use crate::client::*;
+    use big_s::S;
     use meilisearch_test_macro::meilisearch_test;
 
+    #[test]
+    fn test_validate_ranking_rules() {
+        let rules = ["words", "sort", "release_date:asc"].map(String::from);
+        assert!(validate_ranking_rules(&rules).is_ok());
+
+        let rules = ["words", "_geoPoint(48.8, 2.3):asc"].map(String::from);
+        let error = validate_ranking_rules(&rules).unwrap_err();
+        assert!(matches!(
+            error,
+            SettingsValidationError::InvalidRankingRule { rule, .. } if rule == "_geoPoint(48.8, 2.3):asc"
+        ));
+    }
+
+    #[test]
+    fn test_settings_validate_ranking_rules() {
+        assert!(Settings::new().validate_ranking_rules().is_ok());
+
+        let settings = Settings::new().with_ranking_rules([
+            "words",
+            "typo",
+            "proximity",
+            "attribute",
+            "sort",
+            "exactness",
+            "release_date:asc",
+            "nested.rank:desc",
+        ]);
+        assert!(settings.validate_ranking_rules().is_ok());
+
+        for rule in ["exactnes", "release_date:ascending", ":asc", "Words"].iter() {
+            let settings = Settings::new().with_ranking_rules([*rule]);
+            assert!(matches!(
+                settings.validate_ranking_rules(),
+                Err(SettingsValidationError::InvalidRankingRule { rule: invalid, .. }) if invalid == *rule
+            ));
+        }
+
+        let settings = Settings::new().with_ranking_rules(["_geoPoint(0, 0):asc"]);
+        let error = settings.validate_ranking_rules().unwrap_err();
+        assert!(error.to_string().contains("geo sorting"));
+    }
+
+    #[meilisearch_test]
+    async fn test_set_geo_ranking_rule(index: Index) {
+        let error = index
+            .set_ranking_rules(["words", "_geoPoint(0, 0):asc"])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidSettings(SettingsValidationError::InvalidRankingRule { .. })
+        ));
+
+        let settings = Settings::new().with_ranking_rules(["_geoPoint(0, 0):desc"]);
+        let error = index.set_settings(&settings).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidSettings(SettingsValidationError::InvalidRankingRule { .. })
+        ));
+    }
+
     #[meilisearch_test]
     async fn test_set_faceting_settings(client: Client, index: Index) {
         let faceting = FacetingSettings {
@@ -2518,7 +3499,7 @@ mod tests {
 
     #[meilisearch_test]
     async fn test_get_proximity_precision(index: Index) {
-        let expected = "byWord".to_string();
+        let expected = ProximityPrecision::ByWord;
 
         let res = index.get_proximity_precision().await.unwrap();
 
@@ -2527,10 +3508,10 @@ mod tests {
 
     #[meilisearch_test]
     async fn test_set_proximity_precision(client: Client, index: Index) {
-        let expected = "byAttribute".to_string();
+        let expected = ProximityPrecision::ByAttribute;
 
         let task_info = index
-            .set_proximity_precision("byAttribute".to_string())
+            .set_proximity_precision(ProximityPrecision::ByAttribute)
             .await
             .unwrap();
         client.wait_for_task(task_info, None, None).await.unwrap();
@@ -2542,10 +3523,10 @@ mod tests {
 
     #[meilisearch_test]
     async fn test_reset_proximity_precision(index: Index) {
-        let expected = "byWord".to_string();
+        let expected = ProximityPrecision::ByWord;
 
         let task = index
-            .set_proximity_precision("byAttribute".to_string())
+            .set_proximity_precision(ProximityPrecision::ByAttribute)
             .await
             .unwrap();
         index.wait_for_task(task, None, None).await.unwrap();
@@ -2558,6 +3539,25 @@ mod tests {
         assert_eq!(expected, default);
     }
 
+    #[test]
+    fn test_proximity_precision_serialization() {
+        assert_eq!(
+            serde_json::to_value(ProximityPrecision::ByWord).unwrap(),
+            "byWord"
+        );
+        assert_eq!(
+            serde_json::to_value(ProximityPrecision::ByAttribute).unwrap(),
+            "byAttribute"
+        );
+        assert!(serde_json::from_str::<ProximityPrecision>(r#""byWrod""#).is_err());
+
+        let settings = Settings::new().with_proximity_precision(ProximityPrecision::ByAttribute);
+        assert_eq!(
+            serde_json::to_value(&settings).unwrap()["proximityPrecision"],
+            "byAttribute"
+        );
+    }
+
     #[meilisearch_test]
     async fn test_get_search_cutoff_ms(index: Index) {
         let expected = None;
@@ -2694,4 +3694,303 @@ mod tests {
         let res = index.get_localized_attributes().await.unwrap();
         assert_eq!(None, res);
     }
+
+    #[meilisearch_test]
+    async fn test_get_prefix_search(index: Index) {
+        let res = index.get_prefix_search().await.unwrap();
+        assert_eq!(PrefixSearch::IndexingTime, res);
+    }
+
+    #[meilisearch_test]
+    async fn test_set_prefix_search(client: Client, index: Index) {
+        let task_info = index
+            .set_prefix_search(PrefixSearch::Disabled)
+            .await
+            .unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_prefix_search().await.unwrap();
+        assert_eq!(PrefixSearch::Disabled, res);
+    }
+
+    #[meilisearch_test]
+    async fn test_reset_prefix_search(client: Client, index: Index) {
+        let task_info = index
+            .set_prefix_search(PrefixSearch::Disabled)
+            .await
+            .unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let reset_task = index.reset_prefix_search().await.unwrap();
+        client.wait_for_task(reset_task, None, None).await.unwrap();
+
+        let res = index.get_prefix_search().await.unwrap();
+        assert_eq!(PrefixSearch::IndexingTime, res);
+    }
+
+    #[meilisearch_test]
+    async fn test_get_facet_search(index: Index) {
+        let res = index.get_facet_search().await.unwrap();
+        assert!(res);
+    }
+
+    #[meilisearch_test]
+    async fn test_set_facet_search(client: Client, index: Index) {
+        let task_info = index.set_facet_search(false).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_facet_search().await.unwrap();
+        assert!(!res);
+    }
+
+    #[meilisearch_test]
+    async fn test_reset_facet_search(client: Client, index: Index) {
+        let task_info = index.set_facet_search(false).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let reset_task = index.reset_facet_search().await.unwrap();
+        client.wait_for_task(reset_task, None, None).await.unwrap();
+
+        let res = index.get_facet_search().await.unwrap();
+        assert!(res);
+    }
+
+    #[test]
+    fn test_prefix_and_facet_search_serialization() {
+        let settings = Settings::new()
+            .with_prefix_search(PrefixSearch::Disabled)
+            .with_facet_search(false);
+
+        let settings = serde_json::to_value(&settings).unwrap();
+        assert_eq!(settings["prefixSearch"], "disabled");
+        assert_eq!(settings["facetSearch"], false);
+    }
+
+    #[meilisearch_test]
+    async fn test_set_embedders_is_partial(client: Client, index: Index) {
+        let embedder = |dimensions| {
+            Embedder::UserProvided(UserProvidedEmbedderSettings {
+                dimensions,
+                ..UserProvidedEmbedderSettings::default()
+            })
+        };
+
+        let mut first = HashMap::new();
+        first.insert(S("first"), embedder(2));
+        let task_info = index.set_embedders(&first).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let mut second = HashMap::new();
+        second.insert(S("second"), embedder(3));
+        let task_info = index.set_embedders(&second).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_embedders().await.unwrap();
+        assert_eq!(res.len(), 2);
+        assert_eq!(res["first"], embedder(2));
+        assert_eq!(res["second"], embedder(3));
+
+        let reset_task = index.reset_embedders().await.unwrap();
+        client.wait_for_task(reset_task, None, None).await.unwrap();
+
+        assert!(index.get_embedders().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_embedders_round_trip() {
+        let embedders = serde_json::json!({
+            "default": {
+                "source": "openAi",
+                "apiKey": "sk-xxx",
+                "model": "text-embedding-3-small",
+                "dimensions": 1536,
+                "documentTemplate": "A movie titled {{doc.title}}",
+                "distribution": { "mean": 0.7, "sigma": 0.3 },
+                "binaryQuantized": true
+            },
+            "local": {
+                "source": "huggingFace",
+                "model": "BAAI/bge-base-en-v1.5",
+                "distribution": { "mean": 0.5, "sigma": 0.1 },
+                "binaryQuantized": false
+            },
+            "ollama": {
+                "source": "ollama",
+                "url": "http://localhost:11434/api/embeddings",
+                "model": "nomic-embed-text",
+                "binaryQuantized": true
+            },
+            "rest": {
+                "source": "rest",
+                "url": "http://localhost:8080/embed",
+                "request": { "input": "{{text}}" },
+                "response": { "embedding": "{{embedding}}" },
+                "distribution": { "mean": 0.6, "sigma": 0.2 }
+            },
+            "manual": {
+                "source": "userProvided",
+                "dimensions": 3,
+                "binaryQuantized": true
+            },
+            "future": {
+                "source": "someFutureSource",
+                "model": "a-model",
+                "pooling": "useModel"
+            }
+        });
+
+        let parsed: HashMap<String, Embedder> = serde_json::from_value(embedders.clone()).unwrap();
+        assert_eq!(
+            parsed["default"],
+            Embedder::OpenAI(OpenAIEmbedderSettings {
+                api_key: Some(S("sk-xxx")),
+                model: Some(S("text-embedding-3-small")),
+                dimensions: Some(1536),
+                document_template: Some(S("A movie titled {{doc.title}}")),
+                distribution: Some(DistributionShift {
+                    mean: 0.7,
+                    sigma: 0.3
+                }),
+                binary_quantized: Some(true),
+                ..OpenAIEmbedderSettings::default()
+            })
+        );
+        assert_eq!(
+            parsed["manual"],
+            Embedder::UserProvided(UserProvidedEmbedderSettings {
+                dimensions: 3,
+                distribution: None,
+                binary_quantized: Some(true),
+            })
+        );
+        // unknown sources are kept as is
+        assert_eq!(parsed["future"].source(), "someFutureSource");
+        assert_eq!(
+            parsed["future"].clone().with_model("ignored"),
+            parsed["future"]
+        );
+
+        let settings = Settings::new().with_embedders(parsed);
+        assert_eq!(
+            serde_json::to_value(&settings).unwrap()["embedders"],
+            embedders
+        );
+    }
+    #[test]
+    fn test_settings_diff_and_merge() {
+        let mut current_embedders = HashMap::new();
+        current_embedders.insert("default", Embedder::user_provided(3));
+        current_embedders.insert("other", Embedder::user_provided(5));
+        let current = Settings::new()
+            .with_stop_words(["a", "the"])
+            .with_distinct_attribute(Some("sku"))
+            .with_embedders(current_embedders);
+
+        let mut desired_embedders = HashMap::new();
+        desired_embedders.insert("default", Embedder::user_provided(3));
+        desired_embedders.insert("other", Embedder::user_provided(8));
+        let desired = Settings::new()
+            .with_stop_words(["a", "the"])
+            .with_distinct_attribute(None::<String>)
+            .with_facet_search(false)
+            .with_embedders(desired_embedders);
+
+        let delta = current.diff(&desired);
+        let mut expected_embedders = HashMap::new();
+        expected_embedders.insert("other", Embedder::user_provided(8));
+        assert_eq!(
+            delta,
+            Settings::new()
+                .with_distinct_attribute(None::<String>)
+                .with_facet_search(false)
+                .with_embedders(expected_embedders)
+        );
+        assert_eq!(current.diff(&current), Settings::new());
+        assert_eq!(current.diff(&Settings::new()), Settings::new());
+
+        let merged = current.clone().merge(delta);
+        assert_eq!(merged.diff(&desired), Settings::new());
+        assert_eq!(merged.stop_words, current.stop_words);
+        assert_eq!(merged.distinct_attribute, Some(None));
+        assert_eq!(merged.embedders.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_embedder_constructors() {
+        let embedder = Embedder::open_ai("sk-xxx")
+            .with_model("text-embedding-3-small")
+            .with_dimensions(512)
+            .with_binary_quantized(true);
+        assert_eq!(
+            embedder,
+            Embedder::OpenAI(OpenAIEmbedderSettings {
+                api_key: Some(S("sk-xxx")),
+                model: Some(S("text-embedding-3-small")),
+                dimensions: Some(512),
+                binary_quantized: Some(true),
+                ..OpenAIEmbedderSettings::default()
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&embedder).unwrap()["source"],
+            embedder.source()
+        );
+
+        let embedder = Embedder::user_provided(3).with_model("ignored");
+        assert_eq!(embedder, Embedder::user_provided(3));
+        assert_eq!(embedder.source(), "userProvided");
+
+        let embedder = Embedder::composite(
+            Embedder::hugging_face("BAAI/bge-base-en-v1.5"),
+            Embedder::rest("http://localhost:8080/embed")
+                .with_document_template("A movie titled {{doc.title}}"),
+        );
+        assert_eq!(embedder.source(), "composite");
+        assert_eq!(
+            serde_json::to_value(&embedder).unwrap()["indexingEmbedder"]["documentTemplate"],
+            "A movie titled {{doc.title}}"
+        );
+        assert_eq!(Embedder::ollama("nomic-embed-text").source(), "ollama");
+    }
+
+    #[test]
+    fn test_composite_embedder_round_trip() {
+        let embedder = serde_json::json!({
+            "source": "composite",
+            "searchEmbedder": {
+                "source": "huggingFace",
+                "model": "BAAI/bge-base-en-v1.5",
+                "documentTemplateMaxBytes": 200
+            },
+            "indexingEmbedder": {
+                "source": "rest",
+                "url": "http://localhost:8080/embed",
+                "documentTemplate": "A movie titled {{doc.title}}",
+                "documentTemplateMaxBytes": 200,
+                "request": { "input": "{{text}}" },
+                "response": { "embedding": "{{embedding}}" }
+            }
+        });
+
+        let parsed: Embedder = serde_json::from_value(embedder.clone()).unwrap();
+        assert_eq!(
+            parsed,
+            Embedder::Composite {
+                search_embedder: Box::new(Embedder::HuggingFace(HuggingFaceEmbedderSettings {
+                    model: Some(S("BAAI/bge-base-en-v1.5")),
+                    document_template_max_bytes: Some(200),
+                    ..HuggingFaceEmbedderSettings::default()
+                })),
+                indexing_embedder: Box::new(Embedder::REST(GenericRestEmbedderSettings {
+                    url: S("http://localhost:8080/embed"),
+                    document_template: Some(S("A movie titled {{doc.title}}")),
+                    document_template_max_bytes: Some(200),
+                    request: Some(serde_json::json!({ "input": "{{text}}" })),
+                    response: Some(serde_json::json!({ "embedding": "{{embedding}}" })),
+                    ..GenericRestEmbedderSettings::default()
+                })),
+            }
+        );
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), embedder);
+    }
 }
@@ -0,0 +1,313 @@
+//! The `similar` module allows finding the documents of an index that are the most similar to a given document.
+//!
+//! Similarity is computed from the embeddings of the documents, so the index needs at least one [embedder](https://www.meilisearch.com/docs/learn/ai_powered_search/getting_started_with_ai_search) configured.
+
+use crate::{
+    errors::Error,
+    indexes::Index,
+    request::*,
+    search::{serialize_with_wildcard, Filter, FilterExpression, SearchResult, Selectors},
+    DefaultHttpClient,
+};
+use either::Either;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A struct representing a query for the documents similar to a given document.
+///
+/// # Example
+///
+/// ```
+/// # use serde::{Serialize, Deserialize};
+/// # use meilisearch_sdk::{client::*, indexes::*, similar::*};
+/// #
+/// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+/// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+/// #
+/// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+/// # let index = client.index("similar_query_builder_build");
+/// let query = SimilarQuery::new(&index, "143")
+///     .with_embedder("default")
+///     .with_limit(5)
+///     .with_ranking_score_threshold(0.8)
+///     .build(); // you can also execute() instead of build()
+/// ```
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarQuery<'a, Http: HttpClient = DefaultHttpClient> {
+    #[serde(skip_serializing)]
+    index: &'a Index<Http>,
+    /// The primary key of the document the results must be similar to.
+    pub id: &'a str,
+    /// The embedder used to compute the similarity.
+    ///
+    /// Recent Meilisearch versions require it, leaving it unset makes the server return an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedder: Option<&'a str>,
+    /// The number of documents to skip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    /// The maximum number of documents returned.
+    ///
+    /// **Default: `20`**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// Filter applied to documents.
+    ///
+    /// Read the [dedicated guide](https://www.meilisearch.com/docs/learn/advanced/filtering) to learn the syntax.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter<'a>>,
+    /// Attributes to display in the returned documents.
+    ///
+    /// Can be set to a [wildcard value](Selectors::All) that will select all existing attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_with_wildcard")]
+    pub attributes_to_retrieve: Option<Selectors<&'a [&'a str]>>,
+    /// Defines whether to return the ranking score of each document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_ranking_score: Option<bool>,
+    /// Defines whether to return the detailed ranking score of each document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_ranking_score_details: Option<bool>,
+    /// Excludes the documents whose ranking score is below this value, between `0.0` and `1.0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking_score_threshold: Option<f64>,
+    /// Return the embeddings of the documents in the `_vectors` field of the hits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrieve_vectors: Option<bool>,
+}
+
+#[allow(missing_docs)]
+impl<'a, Http: HttpClient> SimilarQuery<'a, Http> {
+    #[must_use]
+    pub fn new(index: &'a Index<Http>, id: &'a str) -> SimilarQuery<'a, Http> {
+        SimilarQuery {
+            index,
+            id,
+            embedder: None,
+            offset: None,
+            limit: None,
+            filter: None,
+            attributes_to_retrieve: None,
+            show_ranking_score: None,
+            show_ranking_score_details: None,
+            ranking_score_threshold: None,
+            retrieve_vectors: None,
+        }
+    }
+    /// Choose the embedder used to compute the similarity.
+    ///
+    /// Recent Meilisearch versions require it, leaving it unset makes the server return an error.
+    pub fn with_embedder<'b>(&'b mut self, embedder: &'a str) -> &'b mut SimilarQuery<'a, Http> {
+        self.embedder = Some(embedder);
+        self
+    }
+    pub fn with_offset<'b>(&'b mut self, offset: usize) -> &'b mut SimilarQuery<'a, Http> {
+        self.offset = Some(offset);
+        self
+    }
+    pub fn with_limit<'b>(&'b mut self, limit: usize) -> &'b mut SimilarQuery<'a, Http> {
+        self.limit = Some(limit);
+        self
+    }
+    /// Only return the similar documents matching the filter, to exclude the out-of-stock products for example.
+    ///
+    /// The filtered attributes must be in the `filterableAttributes` of the index.
+    pub fn with_filter<'b>(&'b mut self, filter: &'a str) -> &'b mut SimilarQuery<'a, Http> {
+        self.filter = Some(Filter::new(Either::Left(filter)));
+        self
+    }
+    pub fn with_array_filter<'b>(
+        &'b mut self,
+        filter: Vec<&'a str>,
+    ) -> &'b mut SimilarQuery<'a, Http> {
+        self.filter = Some(Filter::new(Either::Right(filter)));
+        self
+    }
+    /// Filter the similar documents with a typed [`FilterExpression`] instead of a raw string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::FilterExpression, similar::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// # let index = client.index("similar_query_with_filter_expr");
+    /// let query = SimilarQuery::new(&index, "143")
+    ///     .with_embedder("default")
+    ///     .with_filter_expr(&FilterExpression::gt("stock", 0))
+    ///     .with_attributes_to_retrieve(meilisearch_sdk::search::Selectors::Some(&["id", "title"]))
+    ///     .build();
+    ///
+    /// assert_eq!(serde_json::to_value(&query).unwrap()["filter"], "stock > 0");
+    /// ```
+    pub fn with_filter_expr<'b>(
+        &'b mut self,
+        filter: &FilterExpression,
+    ) -> &'b mut SimilarQuery<'a, Http> {
+        self.filter = Some(filter.into());
+        self
+    }
+    /// Remove the filter previously set on the query.
+    pub fn clear_filter<'b>(&'b mut self) -> &'b mut SimilarQuery<'a, Http> {
+        self.filter = None;
+        self
+    }
+    /// Only return these attributes of the similar documents, or all of them with [`Selectors::All`].
+    pub fn with_attributes_to_retrieve<'b>(
+        &'b mut self,
+        attributes_to_retrieve: Selectors<&'a [&'a str]>,
+    ) -> &'b mut SimilarQuery<'a, Http> {
+        self.attributes_to_retrieve = Some(attributes_to_retrieve);
+        self
+    }
+    pub fn with_show_ranking_score<'b>(
+        &'b mut self,
+        show_ranking_score: bool,
+    ) -> &'b mut SimilarQuery<'a, Http> {
+        self.show_ranking_score = Some(show_ranking_score);
+        self
+    }
+    pub fn with_show_ranking_score_details<'b>(
+        &'b mut self,
+        show_ranking_score_details: bool,
+    ) -> &'b mut SimilarQuery<'a, Http> {
+        self.show_ranking_score_details = Some(show_ranking_score_details);
+        self
+    }
+    /// Exclude the documents whose ranking score is below the threshold.
+    pub fn with_ranking_score_threshold<'b>(
+        &'b mut self,
+        ranking_score_threshold: f64,
+    ) -> &'b mut SimilarQuery<'a, Http> {
+        self.ranking_score_threshold = Some(ranking_score_threshold);
+        self
+    }
+    pub fn with_retrieve_vectors<'b>(
+        &'b mut self,
+        retrieve_vectors: bool,
+    ) -> &'b mut SimilarQuery<'a, Http> {
+        self.retrieve_vectors = Some(retrieve_vectors);
+        self
+    }
+    pub fn build(&mut self) -> SimilarQuery<'a, Http> {
+        self.clone()
+    }
+    /// Execute the query and fetch the similar documents.
+    pub async fn execute<T: 'static + DeserializeOwned + Send + Sync>(
+        &'a self,
+    ) -> Result<SimilarResults<T>, Error> {
+        self.index.similar_search::<T>(self).await
+    }
+}
+
+/// The documents similar to the one of a [`SimilarQuery`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarResults<T> {
+    /// The similar documents, from the most to the least similar.
+    pub hits: Vec<SearchResult<T>>,
+    /// The primary key of the document the results are similar to.
+    pub id: String,
+    /// Number of documents skipped.
+    pub offset: Option<usize>,
+    /// Number of results returned.
+    pub limit: Option<usize>,
+    /// Estimated total number of similar documents.
+    pub estimated_total_hits: Option<usize>,
+    /// Processing time of the query.
+    pub processing_time_ms: usize,
+}
+
+/// Similar documents related methods.
+/// See the [similar](crate::similar) module.
+impl<Http: HttpClient> Index<Http> {
+    /// Find the documents similar to the one of a [`SimilarQuery`].
+    ///
+    /// See also [`SimilarQuery::execute`].
+    pub async fn similar_search<T: 'static + DeserializeOwned + Send + Sync>(
+        &self,
+        body: &SimilarQuery<'_, Http>,
+    ) -> Result<SimilarResults<T>, Error> {
+        self.client
+            .http_client
+            .request::<(), &SimilarQuery<Http>, SimilarResults<T>>(
+                &format!("{}/indexes/{}/similar", self.client.host, self.uid),
+                Method::Post { body, query: () },
+                200,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::*;
+    use meilisearch_test_macro::meilisearch_test;
+    use serde_json::{json, Value};
+
+    #[meilisearch_test]
+    async fn test_similar_query_serialization(index: Index) -> Result<(), Error> {
+        let query = SimilarQuery::new(&index, "42")
+            .with_embedder("default")
+            .with_limit(5)
+            .with_filter("genre = action")
+            .with_attributes_to_retrieve(Selectors::All)
+            .with_ranking_score_threshold(0.5)
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({
+                "id": "42",
+                "embedder": "default",
+                "limit": 5,
+                "filter": "genre = action",
+                "attributesToRetrieve": ["*"],
+                "rankingScoreThreshold": 0.5,
+            })
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_similar_query_filter_expr(index: Index) -> Result<(), Error> {
+        let mut query = SimilarQuery::new(&index, "42");
+        query
+            .with_filter_expr(
+                &FilterExpression::eq("in_stock", true).and(!FilterExpression::eq("id", 7)),
+            )
+            .with_attributes_to_retrieve(Selectors::Some(&["id"]));
+
+        let body = serde_json::to_value(&query).unwrap();
+        assert_eq!(body["filter"], json!("in_stock = true AND NOT id = 7"));
+        assert_eq!(body["attributesToRetrieve"], json!(["id"]));
+
+        let body = serde_json::to_value(query.clear_filter()).unwrap();
+        assert_eq!(body.get("filter"), None);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_similar_search_unknown_embedder(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        index
+            .add_documents(&[json!({ "id": 0 })], Some("id"))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let error = SimilarQuery::new(&index, "0")
+            .with_embedder("unknown")
+            .execute::<Value>()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Meilisearch(_)));
+        Ok(())
+    }
+}
@@ -17,6 +17,9 @@ pub enum TaskType {
     DocumentDeletion {
         details: Option<DocumentDeletion>,
     },
+    DocumentEdition {
+        details: Option<DocumentEdition>,
+    },
     IndexCreation {
         details: Option<IndexCreation>,
     },
@@ -44,14 +47,25 @@ pub enum TaskType {
     SnapshotCreation {
         details: Option<SnapshotCreation>,
     },
+    /// A task whose type or details this version of the SDK can't parse, kept as raw JSON.
+    #[serde(untagged)]
+    Unknown {
+        #[serde(rename = "type")]
+        task_type: String,
+        details: Option<serde_json::Value>,
+    },
 }
 
+/// A page of [Tasks](Task) returned by [`TasksSearchQuery::execute`].
 #[derive(Debug, Clone, Deserialize)]
 pub struct TasksResults {
     pub results: Vec<Task>,
+    /// Total number of tasks matching the query, across all pages.
     pub total: u64,
     pub limit: u32,
+    /// Uid of the first task of this page.
     pub from: Option<u32>,
+    /// Uid to pass to [`TasksSearchQuery::with_from`] to fetch the next page, `None` on the last page.
     pub next: Option<u32>,
 }
 
@@ -62,6 +76,27 @@ pub struct DocumentAdditionOrUpdate {
     pub received_documents: usize,
 }
 
+impl DocumentAdditionOrUpdate {
+    /// Number of received documents that were not indexed.
+    ///
+    /// Returns `None` while the task has not been processed, as `indexed_documents` is unknown until then.
+    #[must_use]
+    pub fn failed_documents(&self) -> Option<usize> {
+        self.indexed_documents
+            .map(|indexed| self.received_documents.saturating_sub(indexed))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentEdition {
+    pub function: Option<String>,
+    pub context: Option<serde_json::Value>,
+    pub original_filter: Option<String>,
+    pub edited_documents: Option<usize>,
+    pub deleted_documents: Option<usize>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentDeletion {
@@ -134,7 +169,7 @@ impl AsRef<u32> for FailedTask {
     }
 }
 
-fn deserialize_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+pub(crate) fn deserialize_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -156,6 +191,9 @@ pub struct SucceededTask {
     pub finished_at: OffsetDateTime,
     pub canceled_by: Option<usize>,
     pub index_uid: Option<String>,
+    /// Uid of the [batch](crate::batches::Batch) the task was processed in.
+    #[serde(default)]
+    pub batch_uid: Option<u32>,
     pub error: Option<MeilisearchError>,
     #[serde(flatten)]
     pub update_type: TaskType,
@@ -193,6 +231,9 @@ pub struct ProcessingTask {
     #[serde(with = "time::serde::rfc3339")]
     pub started_at: OffsetDateTime,
     pub index_uid: Option<String>,
+    /// Uid of the [batch](crate::batches::Batch) processing the task.
+    #[serde(default)]
+    pub batch_uid: Option<u32>,
     #[serde(flatten)]
     pub update_type: TaskType,
     pub uid: u32,
@@ -204,6 +245,34 @@ impl AsRef<u32> for ProcessingTask {
     }
 }
 
+/// A task that was canceled by a [`TaskCancelation`](TaskType::TaskCancelation) task before or while being processed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledTask {
+    #[serde(with = "time::serde::rfc3339")]
+    pub enqueued_at: OffsetDateTime,
+    /// `None` if the task was canceled before Meilisearch started processing it.
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub started_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub finished_at: OffsetDateTime,
+    /// Uid of the task that canceled this one.
+    pub canceled_by: Option<usize>,
+    pub index_uid: Option<String>,
+    /// Uid of the [batch](crate::batches::Batch) the task was canceled in.
+    #[serde(default)]
+    pub batch_uid: Option<u32>,
+    #[serde(flatten)]
+    pub update_type: TaskType,
+    pub uid: u32,
+}
+
+impl AsRef<u32> for CanceledTask {
+    fn as_ref(&self) -> &u32 {
+        &self.uid
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "status")]
 pub enum Task {
@@ -223,6 +292,10 @@ pub enum Task {
         #[serde(flatten)]
         content: SucceededTask,
     },
+    Canceled {
+        #[serde(flatten)]
+        content: CanceledTask,
+    },
 }
 
 impl Task {
@@ -233,6 +306,33 @@ impl Task {
             Self::Processing { content } => *content.as_ref(),
             Self::Failed { content } => *content.as_ref(),
             Self::Succeeded { content } => *content.as_ref(),
+            Self::Canceled { content } => *content.as_ref(),
+        }
+    }
+
+    /// The uid of the index the [Task] applies to, or `None` for the tasks not targeting an index, like a dump creation.
+    #[must_use]
+    pub fn index_uid(&self) -> Option<&str> {
+        match self {
+            Self::Enqueued { content } => content.index_uid.as_deref(),
+            Self::Processing { content } => content.index_uid.as_deref(),
+            Self::Failed { content } => content.task.index_uid.as_deref(),
+            Self::Succeeded { content } => content.index_uid.as_deref(),
+            Self::Canceled { content } => content.index_uid.as_deref(),
+        }
+    }
+
+    /// The uid of the [batch](crate::batches::Batch) the [Task] was processed in, or `None` while it is enqueued.
+    ///
+    /// Tasks canceled before being processed may have no batch either.
+    #[must_use]
+    pub fn batch_uid(&self) -> Option<u32> {
+        match self {
+            Self::Enqueued { .. } => None,
+            Self::Processing { content } => content.batch_uid,
+            Self::Failed { content } => content.task.batch_uid,
+            Self::Succeeded { content } => content.batch_uid,
+            Self::Canceled { content } => content.batch_uid,
         }
     }
 
@@ -372,6 +472,100 @@ impl Task {
         }
     }
 
+    /// Returns the [`MeilisearchError`] of a [`Self::Failed`] [Task], or `None` for any other status.
+    ///
+    /// Unlike [`Task::unwrap_failure`], this never panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, errors::ErrorCode};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let task = client
+    ///     .create_index("try_unwrap_failure", None)
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert!(task.try_unwrap_failure().is_none());
+    ///
+    /// let task = client
+    ///     .create_index("try_unwrap_failure", None)
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     task.try_unwrap_failure().map(|error| &error.error_code),
+    ///     Some(&ErrorCode::IndexAlreadyExists)
+    /// );
+    /// # client.index("try_unwrap_failure").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn try_unwrap_failure(&self) -> Option<&MeilisearchError> {
+        match self {
+            Self::Failed {
+                content: FailedTask { error, .. },
+            } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// The fields Meilisearch could not choose between when inferring the primary key, if the [Task] failed for this reason.
+    ///
+    /// See [`MeilisearchError::primary_key_candidates`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use serde_json::json;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let index = client.index("primary_key_candidates");
+    /// let task = index
+    ///     .add_documents(&[json!({ "id": 1, "author_id": 2 })], None)
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let mut candidates = task.primary_key_candidates().unwrap();
+    /// candidates.sort();
+    /// assert_eq!(candidates, ["author_id", "id"]);
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn primary_key_candidates(&self) -> Option<Vec<String>> {
+        self.try_unwrap_failure()
+            .and_then(MeilisearchError::primary_key_candidates)
+    }
+
+    /// Returns the content of a [`Self::Succeeded`] [Task], or `None` for any other status.
+    #[must_use]
+    pub fn try_unwrap_succeeded(&self) -> Option<&SucceededTask> {
+        match self {
+            Self::Succeeded { content } => Some(content),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the [Task] is [`Self::Failed`].
     ///
     /// # Example
@@ -432,6 +626,12 @@ impl Task {
         matches!(self, Self::Succeeded { .. })
     }
 
+    /// Returns `true` if the [Task] is [`Self::Canceled`].
+    #[must_use]
+    pub fn is_canceled(&self) -> bool {
+        matches!(self, Self::Canceled { .. })
+    }
+
     /// Returns `true` if the [Task] is pending ([`Self::Enqueued`] or [`Self::Processing`]).
     ///
     /// # Example
@@ -459,6 +659,64 @@ impl Task {
     pub fn is_pending(&self) -> bool {
         matches!(self, Self::Enqueued { .. } | Self::Processing { .. })
     }
+
+    /// Returns the number of documents of a finished document addition or update that were not indexed.
+    ///
+    /// Returns `None` for pending tasks, for other kinds of tasks, or when Meilisearch did not return the task details.
+    #[must_use]
+    pub fn failed_document_count(&self) -> Option<usize> {
+        let update_type = match self {
+            Self::Succeeded { content } => &content.update_type,
+            Self::Failed { content } => &content.task.update_type,
+            Self::Enqueued { .. } | Self::Processing { .. } | Self::Canceled { .. } => return None,
+        };
+
+        match update_type {
+            TaskType::DocumentAdditionOrUpdate {
+                details: Some(details),
+            } => details.failed_documents(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if some documents of a finished document addition or update were not indexed.
+    ///
+    /// This is the case of a [failed](Self::Failed) addition: Meilisearch drops every document of the task when one of them is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: String,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// let movies = client.index("has_document_errors");
+    /// let task = movies
+    ///     .add_documents(&[Movie { id: String::from("invalid id!") }], Some("id"))
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert!(task.has_document_errors());
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn has_document_errors(&self) -> bool {
+        self.failed_document_count()
+            .is_some_and(|failed| failed > 0)
+    }
 }
 
 impl AsRef<u32> for Task {
@@ -468,6 +726,7 @@ impl AsRef<u32> for Task {
             Self::Processing { content } => content.as_ref(),
             Self::Succeeded { content } => content.as_ref(),
             Self::Failed { content } => content.as_ref(),
+            Self::Canceled { content } => content.as_ref(),
         }
     }
 }
@@ -515,37 +774,37 @@ pub struct TasksQuery<'a, T, Http: HttpClient> {
     // Date to retrieve all tasks that were enqueued before it.
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "time::serde::rfc3339::option::serialize"
+        serialize_with = "serialize_utc_rfc3339"
     )]
     before_enqueued_at: Option<OffsetDateTime>,
     // Date to retrieve all tasks that were enqueued after it.
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "time::serde::rfc3339::option::serialize"
+        serialize_with = "serialize_utc_rfc3339"
     )]
     after_enqueued_at: Option<OffsetDateTime>,
     // Date to retrieve all tasks that were started before it.
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "time::serde::rfc3339::option::serialize"
+        serialize_with = "serialize_utc_rfc3339"
     )]
     before_started_at: Option<OffsetDateTime>,
     // Date to retrieve all tasks that were started after it.
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "time::serde::rfc3339::option::serialize"
+        serialize_with = "serialize_utc_rfc3339"
     )]
     after_started_at: Option<OffsetDateTime>,
     // Date to retrieve all tasks that were finished before it.
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "time::serde::rfc3339::option::serialize"
+        serialize_with = "serialize_utc_rfc3339"
     )]
     before_finished_at: Option<OffsetDateTime>,
     // Date to retrieve all tasks that were finished after it.
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "time::serde::rfc3339::option::serialize"
+        serialize_with = "serialize_utc_rfc3339"
     )]
     after_finished_at: Option<OffsetDateTime>,
 
@@ -553,6 +812,15 @@ pub struct TasksQuery<'a, T, Http: HttpClient> {
     pagination: T,
 }
 
+/// Serialize the dates of a [`TasksQuery`] in UTC, so the query doesn't depend on the offset the dates were built with.
+fn serialize_utc_rfc3339<S: serde::Serializer>(
+    date: &Option<OffsetDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let date = date.map(|date| date.to_offset(time::UtcOffset::UTC));
+    time::serde::rfc3339::option::serialize(&date, serializer)
+}
+
 #[allow(missing_docs)]
 impl<'a, T, Http: HttpClient> TasksQuery<'a, T, Http> {
     pub fn with_index_uids<'b>(
@@ -583,6 +851,32 @@ impl<'a, T, Http: HttpClient> TasksQuery<'a, T, Http> {
         self.uids = Some(uids.into_iter().collect());
         self
     }
+    /// Only match the tasks enqueued before the given date.
+    ///
+    /// The dates are sent in UTC, whatever their offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # use time::{Duration, OffsetDateTime};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// # let client = Client::new(MEILISEARCH_URL, Some(MEILISEARCH_API_KEY)).unwrap();
+    /// // Delete the finished tasks older than 7 days
+    /// let a_week_ago = OffsetDateTime::now_utc() - Duration::days(7);
+    /// let task = TasksDeleteQuery::new(&client)
+    ///     .with_statuses(["succeeded", "failed", "canceled"])
+    ///     .with_before_enqueued_at(&a_week_ago)
+    ///     .execute()
+    ///     .await
+    ///     .unwrap();
+    /// # client.wait_for_task(task, None, None).await.unwrap();
+    /// # });
+    /// ```
     pub fn with_before_enqueued_at<'b>(
         &'b mut self,
         before_enqueued_at: &'a OffsetDateTime,
@@ -725,6 +1019,61 @@ impl<'a, Http: HttpClient> TasksQuery<'a, TasksPaginationFilters, Http> {
     }
 }
 
+/// Polling schedule of [`Client::wait_for_task_with_backoff`].
+///
+/// The interval between two polls starts at `initial_interval` and is multiplied by `multiplier`
+/// after each poll, up to `max_interval`. Waiting stops once `max_elapsed` is spent or, if set,
+/// after `max_retries` polls following the first one.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::tasks::Backoff;
+/// # use std::time::Duration;
+/// let backoff = Backoff {
+///     max_elapsed: Duration::from_secs(600),
+///     ..Backoff::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    /// **Default = 50ms**
+    pub initial_interval: Duration,
+    /// **Default = 5s**
+    pub max_interval: Duration,
+    /// **Default = 2**, must be finite
+    pub multiplier: f64,
+    /// **Default = 60s**
+    pub max_elapsed: Duration,
+    /// **Default = unlimited**
+    pub max_retries: Option<u32>,
+}
+
+impl Default for Backoff {
+    fn default() -> Backoff {
+        Backoff {
+            initial_interval: Duration::from_millis(50),
+            max_interval: Duration::from_secs(5),
+            multiplier: 2.0,
+            max_elapsed: Duration::from_secs(60),
+            max_retries: None,
+        }
+    }
+}
+
+impl Backoff {
+    /// Interval to wait after `interval`, capped by `max_interval`.
+    pub(crate) fn next_interval(&self, interval: Duration) -> Duration {
+        // computed in f64 so that a large multiplier can't overflow the duration
+        let next = interval.as_secs_f64() * self.multiplier.max(1.0);
+        if next < self.max_interval.as_secs_f64() {
+            Duration::from_secs_f64(next)
+        } else {
+            self.max_interval
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -854,6 +1203,52 @@ mod test {
         ));
     }
 
+    #[meilisearch_test]
+    async fn test_has_document_errors(client: Client, index: Index) -> Result<(), Error> {
+        let task = index
+            .add_documents(
+                &[
+                    serde_json::json!({ "id": 0, "value": "valid" }),
+                    serde_json::json!({ "id": "not a valid id!", "value": "malformed" }),
+                ],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        assert!(task.has_document_errors());
+        assert_eq!(task.failed_document_count(), Some(2));
+
+        let task = index
+            .add_documents(
+                &[serde_json::json!({ "id": 0, "value": "valid" })],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        assert!(!task.has_document_errors());
+        assert_eq!(task.failed_document_count(), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_documents() {
+        let details = DocumentAdditionOrUpdate {
+            indexed_documents: Some(8),
+            received_documents: 10,
+        };
+        assert_eq!(details.failed_documents(), Some(2));
+
+        let details = DocumentAdditionOrUpdate {
+            indexed_documents: None,
+            received_documents: 10,
+        };
+        assert_eq!(details.failed_documents(), None);
+    }
+
     #[meilisearch_test]
     async fn test_wait_for_task_with_args(client: Client, movies: Index) -> Result<(), Error> {
         let task = movies
@@ -989,6 +1384,28 @@ mod test {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_get_tasks_with_dates_in_another_offset() -> Result<(), Error> {
+        let mut s = mockito::Server::new_async().await;
+        let client = Client::new(s.url(), Some("masterKey")).unwrap();
+        let path = "/tasks?afterFinishedAt=2024-02-03T11%3A02%3A38Z";
+
+        let mock_res = s.mock("GET", path).with_status(200).create_async().await;
+
+        let after_finished_at = OffsetDateTime::parse(
+            "2024-02-03T13:02:38+02:00",
+            &time::format_description::well_known::Rfc3339,
+        )
+        .unwrap();
+        let _ = TasksSearchQuery::new(&client)
+            .with_after_finished_at(&after_finished_at)
+            .execute()
+            .await;
+
+        mock_res.assert_async().await;
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_get_tasks_on_struct_with_params() -> Result<(), Error> {
         let mut s = mockito::Server::new_async().await;
@@ -1035,6 +1452,241 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_deserialize_canceled_task() {
+        let task: Task = serde_json::from_str(
+            r#"
+{
+  "uid": 21,
+  "indexUid": "movies",
+  "status": "canceled",
+  "type": "documentAdditionOrUpdate",
+  "canceledBy": 22,
+  "details": {
+    "receivedDocuments": 10,
+    "indexedDocuments": 0
+  },
+  "error": null,
+  "duration": null,
+  "enqueuedAt": "2024-12-10T15:20:30.18182Z",
+  "startedAt": null,
+  "finishedAt": "2024-12-10T15:20:31.18182Z"
+}"#,
+        )
+        .unwrap();
+
+        assert!(task.is_canceled());
+        assert!(!task.is_pending());
+        assert!(task.try_unwrap_failure().is_none());
+        assert!(task.try_unwrap_succeeded().is_none());
+        assert_eq!(task.get_uid(), 21);
+        assert!(matches!(
+            task,
+            Task::Canceled {
+                content: CanceledTask {
+                    started_at: None,
+                    canceled_by: Some(22),
+                    ..
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn test_task_index_and_batch_uids() {
+        let task: Task = serde_json::from_value(serde_json::json!({
+            "uid": 14,
+            "batchUid": 9,
+            "indexUid": "movies",
+            "status": "succeeded",
+            "type": "indexCreation",
+            "canceledBy": null,
+            "details": { "primaryKey": null },
+            "error": null,
+            "duration": "PT0.000179S",
+            "enqueuedAt": "2024-12-10T15:20:30.18182Z",
+            "startedAt": "2024-12-10T15:20:30.18182Z",
+            "finishedAt": "2024-12-10T15:20:30.18182Z"
+        }))
+        .unwrap();
+        assert_eq!(task.index_uid(), Some("movies"));
+        assert_eq!(task.batch_uid(), Some(9));
+
+        let task: Task = serde_json::from_value(serde_json::json!({
+            "uid": 15,
+            "batchUid": null,
+            "indexUid": null,
+            "status": "enqueued",
+            "type": "dumpCreation",
+            "details": { "dumpUid": null },
+            "enqueuedAt": "2024-12-10T15:20:30.18182Z"
+        }))
+        .unwrap();
+        assert_eq!(task.index_uid(), None);
+        assert_eq!(task.batch_uid(), None);
+    }
+
+    #[meilisearch_test]
+    async fn test_task_batch_uid(client: Client, index: Index) -> Result<(), Error> {
+        let task = index
+            .set_search_cutoff_ms(Some(150))
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        assert_eq!(task.index_uid(), Some(index.uid.as_str()));
+        let batch = client.get_batch(task.batch_uid().unwrap()).await?;
+        assert_eq!(batch.uid, task.batch_uid().unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_unknown_task_type() {
+        let task: Task = serde_json::from_str(
+            r#"
+{
+  "uid": 14,
+  "indexUid": null,
+  "status": "succeeded",
+  "type": "networkUpdate",
+  "canceledBy": null,
+  "details": { "remotes": {} },
+  "error": null,
+  "duration": "PT0.000179S",
+  "enqueuedAt": "2024-12-10T15:20:30.18182Z",
+  "startedAt": "2024-12-10T15:20:30.18182Z",
+  "finishedAt": "2024-12-10T15:20:30.18182Z"
+}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            task,
+            Task::Succeeded {
+                content: SucceededTask {
+                    update_type: TaskType::Unknown { ref task_type, details: Some(_) },
+                    uid: 14,
+                    ..
+                }
+            } if task_type == "networkUpdate"
+        ));
+
+        // A known type with details of an unexpected shape is kept as well.
+        let task_type: TaskType =
+            serde_json::from_str(r#"{ "type": "indexCreation", "details": { "primaryKey": 12 } }"#)
+                .unwrap();
+        assert!(matches!(
+            task_type,
+            TaskType::Unknown { ref task_type, .. } if task_type == "indexCreation"
+        ));
+
+        let task_type: TaskType = serde_json::from_str(
+            r#"{ "type": "indexCreation", "details": { "primaryKey": "id" } }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            task_type,
+            TaskType::IndexCreation { details: Some(_) }
+        ));
+    }
+
+    #[test]
+    fn test_backoff_next_interval() {
+        let backoff = Backoff {
+            initial_interval: Duration::from_millis(100),
+            max_interval: Duration::from_millis(350),
+            multiplier: 2.0,
+            ..Backoff::default()
+        };
+
+        let mut interval = backoff.initial_interval;
+        let mut intervals = Vec::new();
+        for _ in 0..4 {
+            interval = backoff.next_interval(interval);
+            intervals.push(interval.as_millis());
+        }
+        assert_eq!(intervals, vec![200, 350, 350, 350]);
+
+        let backoff = Backoff {
+            multiplier: f64::MAX,
+            max_interval: Duration::MAX,
+            ..Backoff::default()
+        };
+        assert_eq!(backoff.next_interval(Duration::from_secs(1)), Duration::MAX);
+    }
+
+    #[meilisearch_test]
+    async fn test_get_tasks_pagination(client: Client, index: Index) -> Result<(), Error> {
+        for id in 0..3 {
+            index
+                .add_documents(
+                    &[Document {
+                        id,
+                        value: S("value"),
+                        kind: S("kind"),
+                    }],
+                    None,
+                )
+                .await?
+                .wait_for_completion(&client, None, None)
+                .await?;
+        }
+
+        let mut query = TasksSearchQuery::new(&client);
+        query
+            .with_index_uids([index.uid.as_str()])
+            .with_types(["documentAdditionOrUpdate"])
+            .with_statuses(["succeeded"])
+            .with_limit(2);
+
+        let first = query.execute().await?;
+        assert_eq!(first.total, 3);
+        assert_eq!(first.limit, 2);
+        assert_eq!(first.results.len(), 2);
+
+        let next = first.next.expect("a second page");
+        let second = query.with_from(next).execute().await?;
+        assert_eq!(second.from, Some(next));
+        assert_eq!(second.results.len(), 1);
+        assert_eq!(second.next, None);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_get_tasks_stream(client: Client, index: Index) -> Result<(), Error> {
+        use futures::TryStreamExt;
+
+        for id in 0..5 {
+            index
+                .add_documents(
+                    &[Document {
+                        id,
+                        value: S("value"),
+                        kind: S("kind"),
+                    }],
+                    None,
+                )
+                .await?
+                .wait_for_completion(&client, None, None)
+                .await?;
+        }
+
+        let mut query = TasksSearchQuery::new(&client);
+        query
+            .with_index_uids([index.uid.as_str()])
+            .with_types(["documentAdditionOrUpdate"])
+            .with_limit(2);
+
+        let uids: Vec<u32> = client
+            .get_tasks_stream(query)
+            .map_ok(|task| task.get_uid())
+            .try_collect()
+            .await?;
+        assert_eq!(uids.len(), 5);
+        assert!(uids.windows(2).all(|pair| pair[0] > pair[1]));
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_failing_task(client: Client, index: Index) -> Result<(), Error> {
         let task_info = client.create_index(index.uid, None).await.unwrap();
@@ -1140,4 +1792,65 @@ mod test {
 
         Ok(())
     }
+
+    #[meilisearch_test]
+    async fn test_cancel_tasks_without_match(client: Client, index: Index) -> Result<(), Error> {
+        let task = TasksCancelQuery::new(&client)
+            .with_index_uids([index.uid.as_str()])
+            .with_statuses(["enqueued", "processing"])
+            .execute()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        match task {
+            Task::Succeeded {
+                content:
+                    SucceededTask {
+                        update_type:
+                            TaskType::TaskCancelation {
+                                details: Some(details),
+                            },
+                        ..
+                    },
+            } => {
+                assert_eq!(details.matched_tasks, 0);
+                assert_eq!(details.canceled_tasks, 0);
+            }
+            task => panic!("unexpected task {:?}", task),
+        }
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_delete_tasks_of_index(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(
+                &[Document {
+                    id: 0,
+                    value: S("value"),
+                    kind: S("kind"),
+                }],
+                None,
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let task = TasksDeleteQuery::new(&client)
+            .with_index_uids([index.uid.as_str()])
+            .with_statuses(["succeeded"])
+            .execute()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+        assert!(task.is_success());
+
+        let tasks = TasksSearchQuery::new(&client)
+            .with_index_uids([index.uid.as_str()])
+            .execute()
+            .await?;
+        assert_eq!(tasks.total, 0);
+        Ok(())
+    }
 }
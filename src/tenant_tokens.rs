@@ -1,23 +1,194 @@
 use crate::errors::Error;
-use jsonwebtoken::{encode, EncodingKey, Header};
+#[cfg(not(target_arch = "wasm32"))]
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use std::{collections::HashMap, convert::TryFrom};
 use time::OffsetDateTime;
-#[cfg(not(target_arch = "wasm32"))]
 use uuid::Uuid;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The `searchRules` of a tenant token, restricting which indexes and documents it can search.
+///
+/// The index uid `"*"` matches every index.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::tenant_tokens::SearchRules;
+/// // Search every document of every index
+/// let rules = SearchRules::all();
+///
+/// // Search every document of `movies`, and only the comedies of `books`
+/// let rules = SearchRules::indexes(["movies"]).with_filter("books", "genre = comedy");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SearchRules {
+    /// Search all the documents of these indexes.
+    Indexes(Vec<String>),
+    /// Search the documents of these indexes, optionally restricted by per-index rules.
+    Filters(HashMap<String, Option<IndexSearchRules>>),
+}
+
+/// The rules applied to the searches of a single index of [`SearchRules::Filters`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexSearchRules {
+    /// Filter applied to every search on the index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+}
+
+impl SearchRules {
+    /// Search all the documents of all the indexes.
+    #[must_use]
+    pub fn all() -> SearchRules {
+        SearchRules::indexes(["*"])
+    }
+
+    /// Search all the documents of these indexes.
+    pub fn indexes(index_uids: impl IntoIterator<Item = impl Into<String>>) -> SearchRules {
+        SearchRules::Indexes(index_uids.into_iter().map(Into::into).collect())
+    }
+
+    /// Only search the documents of `index_uid` matching `filter`.
+    ///
+    /// The indexes already allowed by [`SearchRules::Indexes`] stay searchable without filter.
+    #[must_use]
+    pub fn with_filter(
+        self,
+        index_uid: impl Into<String>,
+        filter: impl Into<String>,
+    ) -> SearchRules {
+        let mut rules = match self {
+            SearchRules::Indexes(index_uids) => index_uids
+                .into_iter()
+                .map(|index_uid| (index_uid, None))
+                .collect(),
+            SearchRules::Filters(rules) => rules,
+        };
+        rules.insert(
+            index_uid.into(),
+            Some(IndexSearchRules {
+                filter: Some(filter.into()),
+            }),
+        );
+
+        SearchRules::Filters(rules)
+    }
+}
+
+impl From<Vec<String>> for SearchRules {
+    fn from(index_uids: Vec<String>) -> SearchRules {
+        SearchRules::Indexes(index_uids)
+    }
+}
+
+impl From<Vec<&str>> for SearchRules {
+    fn from(index_uids: Vec<&str>) -> SearchRules {
+        SearchRules::indexes(index_uids)
+    }
+}
+
+impl From<HashMap<String, Option<IndexSearchRules>>> for SearchRules {
+    fn from(rules: HashMap<String, Option<IndexSearchRules>>) -> SearchRules {
+        SearchRules::Filters(rules)
+    }
+}
+
+/// Parse search rules written as JSON, as they were passed before [`SearchRules`] was typed.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::tenant_tokens::SearchRules;
+/// # use std::convert::TryFrom;
+/// let rules = SearchRules::try_from(serde_json::json!(["movies", "books"])).unwrap();
+/// assert_eq!(rules, SearchRules::indexes(["movies", "books"]));
+/// ```
+impl TryFrom<serde_json::Value> for SearchRules {
+    type Error = Error;
+
+    fn try_from(rules: serde_json::Value) -> Result<SearchRules, Error> {
+        Ok(serde_json::from_value(rules)?)
+    }
+}
+
+/// The claims of a tenant token, as returned by [`decode_tenant_token_claims`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct TenantTokenClaim {
-    api_key_uid: String,
-    search_rules: Value,
-    #[serde(with = "time::serde::timestamp::option")]
-    exp: Option<OffsetDateTime>,
+pub struct TenantTokenClaims {
+    /// The uid of the api key used to sign the token.
+    pub api_key_uid: String,
+    pub search_rules: SearchRules,
+    /// When the token expires, `None` if it never does.
+    #[serde(with = "time::serde::timestamp::option", default)]
+    pub exp: Option<OffsetDateTime>,
+}
+
+impl TenantTokenClaims {
+    /// Returns `true` if the token has expired, and is now refused by Meilisearch.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.exp.is_some_and(|exp| OffsetDateTime::now_utc() >= exp)
+    }
 }
 
-pub fn generate_tenant_token(
+/// Verify the signature of a tenant token with the api key used to generate it, and return its claims.
+///
+/// Expired tokens are decoded too, check [`TenantTokenClaims::is_expired`] to refresh them before Meilisearch refuses them.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, tenant_tokens::*};
+/// # use time::{Duration, OffsetDateTime};
+/// #
+/// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+/// # let api_key = "a19b6ec84ee31324efa560cd1f7e6939";
+/// # let api_key_uid = "76cf8b87-fd12-4688-ad34-260d930ca4f4".to_string();
+/// let client = Client::new(MEILISEARCH_URL, Some(api_key)).unwrap();
+/// let expires_at = OffsetDateTime::now_utc() + Duration::HOUR;
+/// let token = client
+///     .generate_tenant_token(api_key_uid, SearchRules::all(), None, Some(expires_at))
+///     .unwrap();
+///
+/// let claims = decode_tenant_token_claims(&token, api_key).unwrap();
+///
+/// assert_eq!(claims.search_rules, SearchRules::all());
+/// assert!(!claims.is_expired());
+/// assert!(decode_tenant_token_claims(&token, "another key").is_err());
+/// ```
+pub fn decode_tenant_token_claims(
+    token: &str,
+    api_key: impl AsRef<str>,
+) -> Result<TenantTokenClaims, Error> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.validate_exp = false;
+        validation.required_spec_claims.clear();
+
+        let token = decode::<TenantTokenClaims>(
+            token,
+            &DecodingKey::from_secret(api_key.as_ref().as_bytes()),
+            &validation,
+        )
+        .map_err(|error| match error.kind() {
+            jsonwebtoken::errors::ErrorKind::InvalidSignature => {
+                Error::TenantTokensInvalidSignature
+            }
+            _ => Error::InvalidTenantToken(error),
+        })?;
+        Ok(token.claims)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        decode_hs256(token, api_key.as_ref().as_bytes())
+    }
+}
+
+pub(crate) fn generate_tenant_token(
     api_key_uid: String,
-    search_rules: Value,
+    search_rules: SearchRules,
     api_key: impl AsRef<str>,
     expires_at: Option<OffsetDateTime>,
 ) -> Result<String, Error> {
@@ -29,23 +200,77 @@ pub fn generate_tenant_token(
         return Err(Error::InvalidUuid4Version);
     }
 
-    if expires_at.map_or(false, |expires_at| OffsetDateTime::now_utc() > expires_at) {
+    if expires_at.is_some_and(|expires_at| OffsetDateTime::now_utc() > expires_at) {
         return Err(Error::TenantTokensExpiredSignature);
     }
 
-    let claims = TenantTokenClaim {
+    let claims = TenantTokenClaims {
         api_key_uid,
         exp: expires_at,
         search_rules,
     };
 
+    #[cfg(not(target_arch = "wasm32"))]
     let token = encode(
         &Header::default(),
         &claims,
         &EncodingKey::from_secret(api_key.as_ref().as_bytes()),
-    );
+    )?;
+    #[cfg(target_arch = "wasm32")]
+    let token = encode_hs256(&claims, api_key.as_ref().as_bytes())?;
+
+    Ok(token)
+}
+
+/// Sign the claims as a HS256 JWT without `jsonwebtoken`, which doesn't compile to wasm.
+///
+/// The header and its field order are the ones produced by `jsonwebtoken::Header::default()`
+/// so both targets generate exactly the same token.
+#[cfg(any(target_arch = "wasm32", test))]
+fn encode_hs256(claims: &impl Serialize, secret: &[u8]) -> Result<String, Error> {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    let header = URL_SAFE_NO_PAD.encode(r#"{"typ":"JWT","alg":"HS256"}"#);
+    let claims = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?);
+    let message = format!("{header}.{claims}");
+
+    // HMAC accepts keys of any length.
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC can take a key of any size");
+    mac.update(message.as_bytes());
+    let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+    Ok(format!("{message}.{signature}"))
+}
+
+/// Verify and decode a HS256 JWT without `jsonwebtoken`, which doesn't compile to wasm.
+#[cfg(any(target_arch = "wasm32", test))]
+fn decode_hs256<T: serde::de::DeserializeOwned>(token: &str, secret: &[u8]) -> Result<T, Error> {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    let (message, signature) = token
+        .rsplit_once('.')
+        .ok_or(Error::TenantTokensInvalidSignature)?;
+    let claims = message
+        .split_once('.')
+        .map(|(_header, claims)| claims)
+        .ok_or(Error::TenantTokensInvalidSignature)?;
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| Error::TenantTokensInvalidSignature)?;
 
-    Ok(token?)
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC can take a key of any size");
+    mac.update(message.as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| Error::TenantTokensInvalidSignature)?;
+
+    let claims = URL_SAFE_NO_PAD
+        .decode(claims)
+        .map_err(|_| Error::TenantTokensInvalidSignature)?;
+    Ok(serde_json::from_slice(&claims)?)
 }
 
 #[cfg(test)]
@@ -56,7 +281,6 @@ mod tests {
     use serde_json::json;
     use std::collections::HashSet;
 
-    const SEARCH_RULES: [&str; 1] = ["*"];
     const VALID_KEY: &str = "a19b6ec84ee31324efa560cd1f7e6939";
 
     fn build_validation() -> Validation {
@@ -71,14 +295,14 @@ mod tests {
     fn test_generate_token_with_given_key() {
         let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
         let token =
-            generate_tenant_token(api_key_uid, json!(SEARCH_RULES), VALID_KEY, None).unwrap();
+            generate_tenant_token(api_key_uid, SearchRules::all(), VALID_KEY, None).unwrap();
 
-        let valid_key = decode::<TenantTokenClaim>(
+        let valid_key = decode::<TenantTokenClaims>(
             &token,
             &DecodingKey::from_secret(VALID_KEY.as_ref()),
             &build_validation(),
         );
-        let invalid_key = decode::<TenantTokenClaim>(
+        let invalid_key = decode::<TenantTokenClaims>(
             &token,
             &DecodingKey::from_secret("not-the-same-key".as_ref()),
             &build_validation(),
@@ -88,11 +312,127 @@ mod tests {
         assert!(invalid_key.is_err());
     }
 
+    #[test]
+    fn test_encode_hs256_matches_jsonwebtoken() {
+        let exp = OffsetDateTime::from_unix_timestamp(1_900_000_000).unwrap();
+        for (key, expires_at) in [
+            (VALID_KEY, None),
+            (VALID_KEY, Some(exp)),
+            ("Ëa1ทt9bVcL-vãUทtP3OpXW5qPc%bWH5ทvw09", Some(exp)),
+        ]
+        .iter()
+        {
+            let claims = TenantTokenClaims {
+                api_key_uid: S("76cf8b87-fd12-4688-ad34-260d930ca4f4"),
+                search_rules: SearchRules::all().with_filter("movies", "genre = 'comedy'"),
+                exp: *expires_at,
+            };
+
+            let expected = jsonwebtoken::encode(
+                &Header::default(),
+                &claims,
+                &EncodingKey::from_secret(key.as_bytes()),
+            )
+            .unwrap();
+
+            assert_eq!(encode_hs256(&claims, key.as_bytes()).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_decode_tenant_token_claims() {
+        let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
+        let search_rules = SearchRules::indexes(["movies"]).with_filter("books", "genre = comedy");
+        let exp = OffsetDateTime::from_unix_timestamp(OffsetDateTime::now_utc().unix_timestamp())
+            .unwrap()
+            + time::Duration::HOUR;
+        let token = generate_tenant_token(
+            api_key_uid.clone(),
+            search_rules.clone(),
+            VALID_KEY,
+            Some(exp),
+        )
+        .unwrap();
+
+        let expected = TenantTokenClaims {
+            api_key_uid,
+            search_rules,
+            exp: Some(exp),
+        };
+        let claims = decode_tenant_token_claims(&token, VALID_KEY).unwrap();
+        assert_eq!(claims, expected);
+        assert!(!claims.is_expired());
+        assert_eq!(
+            decode_hs256::<TenantTokenClaims>(&token, VALID_KEY.as_bytes()).unwrap(),
+            expected
+        );
+
+        assert!(matches!(
+            decode_tenant_token_claims(&token, "not-the-same-key"),
+            Err(Error::TenantTokensInvalidSignature)
+        ));
+        assert!(matches!(
+            decode_hs256::<TenantTokenClaims>(&token, b"not-the-same-key"),
+            Err(Error::TenantTokensInvalidSignature)
+        ));
+
+        let expired = TenantTokenClaims {
+            exp: Some(OffsetDateTime::now_utc() - time::Duration::HOUR),
+            ..expected
+        };
+        assert!(expired.is_expired());
+    }
+
+    #[test]
+    fn test_search_rules_serialization() {
+        assert_eq!(
+            serde_json::to_value(SearchRules::all()).unwrap(),
+            json!(["*"])
+        );
+        assert_eq!(
+            serde_json::to_value(SearchRules::indexes(["movies", "books"])).unwrap(),
+            json!(["movies", "books"])
+        );
+        assert_eq!(
+            serde_json::to_value(
+                SearchRules::indexes(["movies"]).with_filter("books", "genre = comedy")
+            )
+            .unwrap(),
+            json!({ "movies": null, "books": { "filter": "genre = comedy" } })
+        );
+
+        let mut rules = HashMap::new();
+        rules.insert(S("*"), Some(IndexSearchRules::default()));
+        assert_eq!(
+            serde_json::to_value(SearchRules::from(rules)).unwrap(),
+            json!({ "*": {} })
+        );
+    }
+
+    #[test]
+    fn test_search_rules_from_value() {
+        assert_eq!(
+            SearchRules::try_from(json!(["*"])).unwrap(),
+            SearchRules::all()
+        );
+        assert_eq!(
+            SearchRules::try_from(
+                json!({ "movies": null, "books": { "filter": "genre = comedy" } })
+            )
+            .unwrap(),
+            SearchRules::indexes(["movies"]).with_filter("books", "genre = comedy")
+        );
+        assert!(matches!(
+            SearchRules::try_from(json!("movies")),
+            Err(Error::ParseError(_))
+        ));
+    }
+
     #[test]
     fn test_generate_token_without_uid() {
         let api_key_uid = S("");
         let key = S("");
-        let token = generate_tenant_token(api_key_uid, json!(SEARCH_RULES), key, None);
+        let token = generate_tenant_token(api_key_uid, SearchRules::all(), key, None);
 
         assert!(token.is_err());
     }
@@ -102,9 +442,9 @@ mod tests {
         let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
         let exp = OffsetDateTime::now_utc() + time::Duration::HOUR;
         let token =
-            generate_tenant_token(api_key_uid, json!(SEARCH_RULES), VALID_KEY, Some(exp)).unwrap();
+            generate_tenant_token(api_key_uid, SearchRules::all(), VALID_KEY, Some(exp)).unwrap();
 
-        let decoded = decode::<TenantTokenClaim>(
+        let decoded = decode::<TenantTokenClaims>(
             &token,
             &DecodingKey::from_secret(VALID_KEY.as_ref()),
             &Validation::new(Algorithm::HS256),
@@ -117,7 +457,7 @@ mod tests {
     fn test_generate_token_with_expires_at_in_the_past() {
         let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
         let exp = OffsetDateTime::now_utc() - time::Duration::HOUR;
-        let token = generate_tenant_token(api_key_uid, json!(SEARCH_RULES), VALID_KEY, Some(exp));
+        let token = generate_tenant_token(api_key_uid, SearchRules::all(), VALID_KEY, Some(exp));
 
         assert!(token.is_err());
     }
@@ -125,11 +465,10 @@ mod tests {
     #[test]
     fn test_generate_token_contains_claims() {
         let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
-        let token =
-            generate_tenant_token(api_key_uid.clone(), json!(SEARCH_RULES), VALID_KEY, None)
-                .unwrap();
+        let token = generate_tenant_token(api_key_uid.clone(), SearchRules::all(), VALID_KEY, None)
+            .unwrap();
 
-        let decoded = decode::<TenantTokenClaim>(
+        let decoded = decode::<TenantTokenClaims>(
             &token,
             &DecodingKey::from_secret(VALID_KEY.as_ref()),
             &build_validation(),
@@ -137,7 +476,7 @@ mod tests {
         .expect("Cannot decode the token");
 
         assert_eq!(decoded.claims.api_key_uid, api_key_uid);
-        assert_eq!(decoded.claims.search_rules, json!(SEARCH_RULES));
+        assert_eq!(decoded.claims.search_rules, SearchRules::all());
     }
 
     #[test]
@@ -145,9 +484,9 @@ mod tests {
         let api_key_uid = S("76cf8b87-fd12-4688-ad34-260d930ca4f4");
         let key = "Ëa1ทt9bVcL-vãUทtP3OpXW5qPc%bWH5ทvw09";
         let token =
-            generate_tenant_token(api_key_uid.clone(), json!(SEARCH_RULES), key, None).unwrap();
+            generate_tenant_token(api_key_uid.clone(), SearchRules::all(), key, None).unwrap();
 
-        let decoded = decode::<TenantTokenClaim>(
+        let decoded = decode::<TenantTokenClaims>(
             &token,
             &DecodingKey::from_secret(key.as_ref()),
             &build_validation(),
@@ -161,7 +500,7 @@ mod tests {
     fn test_generate_token_with_wrongly_formatted_uid() {
         let api_key_uid = S("xxx");
         let key = "Ëa1ทt9bVcL-vãUทtP3OpXW5qPc%bWH5ทvw09";
-        let token = generate_tenant_token(api_key_uid, json!(SEARCH_RULES), key, None);
+        let token = generate_tenant_token(api_key_uid, SearchRules::all(), key, None);
 
         assert!(token.is_err());
     }
@@ -170,7 +509,7 @@ mod tests {
     fn test_generate_token_with_wrong_uid_version() {
         let api_key_uid = S("6a11eb96-2485-11ed-861d-0242ac120002");
         let key = "Ëa1ทt9bVcL-vãUทtP3OpXW5qPc%bWH5ทvw09";
-        let token = generate_tenant_token(api_key_uid, json!(SEARCH_RULES), key, None);
+        let token = generate_tenant_token(api_key_uid, SearchRules::all(), key, None);
 
         assert!(token.is_err());
     }
@@ -0,0 +1,209 @@
+use async_trait::async_trait;
+use futures::{pin_mut, AsyncReadExt};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{js_sys, Headers, Request, RequestInit, Response};
+
+use crate::{
+    errors::Error,
+    request::{parse_response, HttpClient, Method, ResponseHeaders},
+};
+
+/// An [`HttpClient`] sending its requests with the `fetch` API of the browser.
+///
+/// It is the [`DefaultHttpClient`](crate::DefaultHttpClient) when the `wasm` feature is enabled and the crate is
+/// compiled for `wasm32`, so [`Client::new`](crate::client::Client::new) uses it without any setup:
+///
+/// ```toml
+/// [dependencies]
+/// meilisearch-sdk = { version = "0.27", default-features = false, features = ["wasm"] }
+/// ```
+///
+/// The requests are sent from the `Window` of the page, the client can't be used where there is none, like in a web worker.
+/// The payloads of the streamed methods, like [`Index::add_documents_ndjson`](crate::indexes::Index::add_documents_ndjson),
+/// are collected in memory before being sent since `fetch` can't stream a request body.
+#[derive(Debug, Clone, Default)]
+pub struct WasmClient {
+    api_key: Option<String>,
+}
+
+impl WasmClient {
+    /// Create a client sending the api key in the `Authorization` header of every request.
+    pub fn new(api_key: Option<&str>) -> Result<Self, Error> {
+        Ok(WasmClient {
+            api_key: api_key.map(|key| key.to_string()),
+        })
+    }
+
+    async fn send<Output: DeserializeOwned>(
+        &self,
+        url: &str,
+        verb: &str,
+        body: Option<(&[u8], &str)>,
+        expected_status_code: u16,
+    ) -> Result<(Output, ResponseHeaders), Error> {
+        let headers = Headers::new().map_err(fetch_error)?;
+        headers
+            .set("X-Meilisearch-Client", &qualified_version())
+            .map_err(fetch_error)?;
+        if let Some(api_key) = &self.api_key {
+            headers
+                .set("Authorization", &format!("Bearer {api_key}"))
+                .map_err(fetch_error)?;
+        }
+
+        let init = RequestInit::new();
+        init.set_method(verb);
+        if let Some((body, content_type)) = body {
+            headers
+                .set("Content-Type", content_type)
+                .map_err(fetch_error)?;
+            init.set_body(&js_sys::Uint8Array::from(body).into());
+        }
+        init.set_headers(&headers.into());
+
+        let request = Request::new_with_str_and_init(url, &init).map_err(fetch_error)?;
+        let window = web_sys::window()
+            .ok_or_else(|| Error::FetchError("no `Window` is available".to_string()))?;
+        let response: Response = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(fetch_error)?
+            .dyn_into()
+            .map_err(fetch_error)?;
+
+        let status = response.status();
+        let headers = response_headers(&response.headers())?;
+        let mut body = JsFuture::from(response.text().map_err(fetch_error)?)
+            .await
+            .map_err(fetch_error)?
+            .as_string()
+            .unwrap_or_default();
+
+        if body.is_empty() {
+            body = "null".to_string();
+        }
+
+        parse_response(status, expected_status_code, &body, url.to_string())
+            .map(|output| (output, headers))
+    }
+}
+
+#[async_trait(?Send)]
+impl HttpClient for WasmClient {
+    async fn request<Query, Body, Output>(
+        &self,
+        url: &str,
+        method: Method<Query, Body>,
+        expected_status_code: u16,
+    ) -> Result<Output, Error>
+    where
+        Query: Serialize + Send + Sync,
+        Body: Serialize + Send + Sync,
+        Output: DeserializeOwned + 'static + Send,
+    {
+        self.request_with_headers(url, method, expected_status_code)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn request_with_headers<Query, Body, Output>(
+        &self,
+        url: &str,
+        method: Method<Query, Body>,
+        expected_status_code: u16,
+    ) -> Result<(Output, ResponseHeaders), Error>
+    where
+        Query: Serialize + Send + Sync,
+        Body: Serialize + Send + Sync,
+        Output: DeserializeOwned + 'static + Send,
+    {
+        let url = with_query(url, method.query())?;
+        let body = method.body().map(serde_json::to_vec).transpose()?;
+
+        self.send(
+            &url,
+            verb(&method),
+            body.as_deref().map(|body| (body, "application/json")),
+            expected_status_code,
+        )
+        .await
+    }
+
+    async fn stream_request<
+        Query: Serialize + Send + Sync,
+        Body: futures_io::AsyncRead + Send + Sync + 'static,
+        Output: DeserializeOwned + 'static,
+    >(
+        &self,
+        url: &str,
+        method: Method<Query, Body>,
+        content_type: &str,
+        expected_status_code: u16,
+    ) -> Result<Output, Error> {
+        let url = with_query(url, method.query())?;
+        let verb = verb(&method);
+
+        let mut buf = Vec::new();
+        let body = match method.into_body() {
+            Some(body) => {
+                pin_mut!(body);
+                body.read_to_end(&mut buf)
+                    .await
+                    .map_err(|err| Error::Other(Box::new(err)))?;
+                Some((buf.as_slice(), content_type))
+            }
+            None => None,
+        };
+
+        self.send(&url, verb, body, expected_status_code)
+            .await
+            .map(|(output, _)| output)
+    }
+}
+
+fn with_query<Q: Serialize>(url: &str, query: &Q) -> Result<String, Error> {
+    let query = yaup::to_string(query)?;
+
+    Ok(if query.is_empty() {
+        url.to_string()
+    } else {
+        format!("{url}{query}")
+    })
+}
+
+fn verb<Q, B>(method: &Method<Q, B>) -> &'static str {
+    match method {
+        Method::Get { .. } => "GET",
+        Method::Delete { .. } => "DELETE",
+        Method::Post { .. } => "POST",
+        Method::Put { .. } => "PUT",
+        Method::Patch { .. } => "PATCH",
+    }
+}
+
+fn response_headers(headers: &Headers) -> Result<ResponseHeaders, Error> {
+    let entries = js_sys::try_iter(headers)
+        .map_err(fetch_error)?
+        .ok_or_else(|| Error::FetchError("the response headers are not iterable".to_string()))?;
+
+    let mut result = Vec::new();
+    for entry in entries {
+        let entry: js_sys::Array = entry.map_err(fetch_error)?.unchecked_into();
+        if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) {
+            result.push((name, value));
+        }
+    }
+
+    Ok(ResponseHeaders::new(result))
+}
+
+fn fetch_error(error: JsValue) -> Error {
+    Error::FetchError(error.as_string().unwrap_or_else(|| format!("{error:?}")))
+}
+
+fn qualified_version() -> String {
+    const VERSION: Option<&str> = option_env!("CARGO_PKG_VERSION");
+
+    format!("Meilisearch Rust (v{})", VERSION.unwrap_or("unknown"))
+}